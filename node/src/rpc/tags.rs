@@ -0,0 +1,88 @@
+//! RPC methods of the tags pallet that need the node itself, rather than the runtime API.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
+use sc_client_api::ProofProvider;
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+/// The `TagMap` entry of a tag at some block, along with the proof of it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagProof<Hash> {
+	/// The block the entry was read at.
+	pub at: Hash,
+	/// The SCALE encoded name, owner and deposit of the tag, `None` if it doesn't exist.
+	pub value: Option<Bytes>,
+	/// The trie nodes proving the entry against the state root of the block. They are checked
+	/// with `pallet_tags_proof::verify_tag_proof`.
+	pub proof: Vec<Bytes>,
+}
+
+#[rpc(client, server)]
+pub trait TagsApi<BlockHash> {
+	/// Returns the `TagMap` entry of a tag with a read proof, at the given block or at the best
+	/// one.
+	#[method(name = "tags_tagProof")]
+	fn tag_proof(&self, tag: u64, at: Option<BlockHash>) -> RpcResult<TagProof<BlockHash>>;
+}
+
+/// Serves the RPC methods of the tags pallet from a client.
+pub struct Tags<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Tags<C, Block> {
+	/// Creates the RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code of failed proofs.
+const PROOF_ERROR: i32 = 1;
+
+fn proof_error(message: &str, data: impl ToString) -> ErrorObject<'static> {
+	ErrorObject::owned(PROOF_ERROR, message, Some(data.to_string()))
+}
+
+impl<C, Block> TagsApiServer<Block::Hash> for Tags<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block> + ProofProvider<Block> + Send + Sync + 'static,
+{
+	fn tag_proof(&self, tag: u64, at: Option<Block::Hash>) -> RpcResult<TagProof<Block::Hash>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let header = self
+			.client
+			.header(at)
+			.map_err(|e| proof_error("Unable to read the header.", e))?
+			.ok_or_else(|| proof_error("Unknown block.", format!("{at:?}")))?;
+
+		let key = pallet_tags_proof::tag_map_key(pallet_tags_proof::PALLET_NAME, tag);
+		let proof: Vec<Vec<u8>> = self
+			.client
+			.read_proof(at, &mut std::iter::once(key.as_slice()))
+			.map_err(|e| proof_error("Unable to prove the tag.", e))?
+			.into_iter_nodes()
+			.collect();
+
+		// Reading the value back from the proof makes sure the proof is complete.
+		let value = pallet_tags_proof::read_proof_value::<<Block::Header as HeaderT>::Hashing>(
+			header.state_root(),
+			&key,
+			proof.clone(),
+		)
+		.map_err(|e| proof_error("Unable to read the tag from its proof.", format!("{e:?}")))?;
+
+		Ok(TagProof {
+			at,
+			value: value.map(Into::into),
+			proof: proof.into_iter().map(Into::into).collect(),
+		})
+	}
+}
@@ -0,0 +1,78 @@
+//! Verifies proofs of what the tags pallet stores, so off-chain services and bridges can rely on
+//! the name and owner of a tag at some block without trusting the node that served them.
+//!
+//! The `tags_tagProof` RPC of the node returns the trie nodes proving the `TagMap` entry of a
+//! tag. Given a header the caller already trusts, [`verify_tag_proof`] checks them against its
+//! state root and decodes the entry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, DecodeAll, Encode};
+use sp_core::{
+	hashing::{blake2_128, twox_128},
+	Hasher,
+};
+use sp_runtime::traits::Header;
+use sp_std::vec::Vec;
+use sp_trie::{LayoutV1, StorageProof};
+
+/// The name of the tags pallet in the runtime, which prefixes its storage keys.
+pub const PALLET_NAME: &[u8] = b"TagModule";
+
+/// A tag as stored in `TagMap`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug)]
+pub struct TagRecord<AccountId, Balance> {
+	/// The name of the tag.
+	pub name: Vec<u8>,
+	/// The owner of the tag, as stored by the pallet. For tags minted as NFTs it's a cache of the
+	/// holder of the item, which can be behind transfers made outside the pallet.
+	pub owner: AccountId,
+	/// The deposit reserved for the tag.
+	pub deposit: Balance,
+}
+
+/// Why a proof was rejected.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum ProofError {
+	/// The proof is incomplete, or doesn't match the state root.
+	InvalidProof,
+	/// The proven value isn't a tag.
+	InvalidValue,
+}
+
+/// The storage key of the `TagMap` entry of a tag, for a pallet with the given name in the
+/// runtime.
+pub fn tag_map_key(pallet_name: &[u8], tag: u64) -> Vec<u8> {
+	let tag = tag.encode();
+	[&twox_128(pallet_name)[..], &twox_128(b"TagMap")[..], &blake2_128(&tag)[..], &tag[..]].concat()
+}
+
+/// Reads the value of `key` from a proof against `state_root`. `None` if the proof shows the key
+/// has no value.
+pub fn read_proof_value<H: Hasher>(
+	state_root: &H::Out,
+	key: &[u8],
+	proof: Vec<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, ProofError> {
+	let db = StorageProof::new(proof).into_memory_db::<H>();
+	sp_trie::read_trie_value::<LayoutV1<H>, _>(&db, state_root, key, None, None)
+		.map_err(|_| ProofError::InvalidProof)
+}
+
+/// Checks a proof of the `TagMap` entry of a tag against the state root of a trusted header,
+/// for a pallet with the given name in the runtime, returning the tag at that block. `None` if the
+/// proof shows the tag didn't exist.
+pub fn verify_tag_proof<H: Header, AccountId: Decode, Balance: Decode>(
+	pallet_name: &[u8],
+	header: &H,
+	tag: u64,
+	proof: Vec<Vec<u8>>,
+) -> Result<Option<TagRecord<AccountId, Balance>>, ProofError> {
+	let key = tag_map_key(pallet_name, tag);
+	read_proof_value::<H::Hashing>(header.state_root(), &key, proof)?
+		.map(|value| TagRecord::decode_all(&mut &value[..]).map_err(|_| ProofError::InvalidValue))
+		.transpose()
+}
@@ -0,0 +1,87 @@
+use crate::{read_proof_value, tag_map_key, verify_tag_proof, ProofError, TagRecord, PALLET_NAME};
+use hex_literal::hex;
+use sp_core::H256;
+use sp_runtime::{
+	generic::Header,
+	traits::{BlakeTwo256, Hash},
+};
+
+// A state holding the `TagMap` entries the mock runtime of the pallet stores for tag 0, named
+// "ABC" and owned by account 1, and for tag 1, named "tagged" and owned by account 2.
+const STATE_ROOT: [u8; 32] = hex!("7de337442ad49e104dd0d22f58f9d2068a5ecdb2b4bedc110935f68eccdd3b39");
+
+// The branch node below the root, shared by both proofs.
+const BRANCH_NODE: &[u8] = &hex!(
+	"bf0192f34f7935d0136c5f35f09ef3d824f86fc2cedee41b49924fa085ef28af3de40012806669b72a12acb5a345cca4212a90c9ec22c7c72c1739745f470cafa4e8c33e9b808ff45385554443fb67c0b8b1d31a3fc99b68bc7807b2045e7459b8d68c1f3f92"
+);
+
+// The leaf holding the entry of tag 0.
+const TAG_0_NODE: &[u8] =
+	&hex!("6f0804ce198ec337e3dc762bdd1a09aece0000000000000000500c41424301000000000000000100000000000000");
+
+// The encoded entry of tag 0.
+const TAG_0_VALUE: &[u8] = &hex!("0c41424301000000000000000100000000000000");
+
+fn header(state_root: H256) -> Header<u64, BlakeTwo256> {
+	Header {
+		parent_hash: Default::default(),
+		number: 1,
+		state_root,
+		extrinsics_root: Default::default(),
+		digest: Default::default(),
+	}
+}
+
+fn tag_0_proof() -> Vec<Vec<u8>> {
+	vec![BRANCH_NODE.to_vec(), TAG_0_NODE.to_vec()]
+}
+
+#[test]
+fn tag_map_keys() {
+	assert_eq!(
+		tag_map_key(PALLET_NAME, 0),
+		hex!("92f34f7935d0136c5f35f09ef3d824f86fc2cedee41b49924fa085ef28af3de4c804ce198ec337e3dc762bdd1a09aece0000000000000000")
+	);
+	assert_ne!(tag_map_key(b"Tags", 0), tag_map_key(PALLET_NAME, 0));
+}
+
+#[test]
+fn proofs_of_existing_tags() {
+	let root = H256::from(STATE_ROOT);
+	assert_eq!(
+		read_proof_value::<BlakeTwo256>(&root, &tag_map_key(PALLET_NAME, 0), tag_0_proof()),
+		Ok(Some(TAG_0_VALUE.to_vec()))
+	);
+
+	let record = TagRecord { name: b"ABC".to_vec(), owner: 1u64, deposit: 1u64 };
+	assert_eq!(verify_tag_proof(PALLET_NAME, &header(root), 0, tag_0_proof()), Ok(Some(record)));
+}
+
+#[test]
+fn proofs_of_missing_tags() {
+	let root = H256::from(STATE_ROOT);
+	let proof = vec![BRANCH_NODE.to_vec()];
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &header(root), 2, proof), Ok(None));
+
+	// The key depends on the name of the pallet, so the tag isn't there under another name
+	assert_eq!(verify_tag_proof::<_, u64, u64>(b"Tags", &header(root), 0, tag_0_proof()), Ok(None));
+}
+
+#[test]
+fn invalid_proofs() {
+	let root = H256::from(STATE_ROOT);
+
+	// Proofs only hold against the state root they were made for
+	let other = header(BlakeTwo256::hash(b"other"));
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &other, 0, tag_0_proof()), Err(ProofError::InvalidProof));
+
+	// And must carry every node down to the entry
+	let proof = vec![BRANCH_NODE.to_vec()];
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &header(root), 0, proof), Err(ProofError::InvalidProof));
+
+	// And the value must decode as a tag
+	assert_eq!(
+		verify_tag_proof::<_, [u8; 32], u64>(PALLET_NAME, &header(root), 0, tag_0_proof()),
+		Err(ProofError::InvalidValue)
+	);
+}
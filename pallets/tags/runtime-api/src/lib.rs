@@ -0,0 +1,87 @@
+//! Runtime API definition for the tags pallet, so clients can query tags without reading and
+//! decoding its storage themselves.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TagsApi<CollectionId, ItemId, AccountId, Balance, TagInfo, Tombstone>
+	where
+		CollectionId: Codec,
+		ItemId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+		TagInfo: Codec,
+		Tombstone: Codec,
+	{
+		/// The name, owner, deposit, status and activity of a tag. `None` if it doesn't exist.
+		fn tag_info(tag: u64) -> Option<TagInfo>;
+
+		/// Who destroyed a tag, when, and the tag it was merged into, if any. `None` if the tag
+		/// exists or never existed.
+		fn tombstone(tag: u64) -> Option<Tombstone>;
+
+		/// The name of a tag in the given language, or its canonical name if it has no
+		/// translation for it. `None` if the tag doesn't exist.
+		fn localized_name(tag: u64, lang: Vec<u8>) -> Option<Vec<u8>>;
+
+		/// The existing tag an id resolves to, following merges. `None` if there is none.
+		fn resolve_tag(tag: u64) -> Option<u64>;
+
+		/// The existing tag a name resolves to, following merges. `None` if there is none.
+		fn tag_by_name(name: Vec<u8>) -> Option<u64>;
+
+		/// Up to `limit` items the tag is applied to, starting after `cursor`. Pass the last item
+		/// of a page as the cursor of the next one.
+		fn items_by_tag(
+			tag: u64,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Vec<(CollectionId, ItemId)>;
+
+		/// Up to `limit` tags applied to the item, starting after `cursor`. Pass the last tag of
+		/// a page as the cursor of the next one.
+		fn tags_by_item(
+			collection: CollectionId,
+			item: ItemId,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> Vec<u64>;
+
+		/// All the tags of the item, both its own and the ones inherited from its collection,
+		/// sorted by id.
+		fn effective_tags(collection: CollectionId, item: ItemId) -> Vec<u64>;
+
+		/// What `who` pays to apply the tag to an item: the tagging deposit, returned when the
+		/// tag is removed, plus the usage fee of the tag unless `who` owns it. `None` if the tag
+		/// doesn't exist or can't be applied anymore.
+		fn tagging_cost(tag: u64, who: AccountId) -> Option<Balance>;
+
+		/// Up to `limit` live tags with the most funds staked on them, from the most staked,
+		/// along with their total stake.
+		fn top_tags_by_stake(limit: u32) -> Vec<(u64, Balance)>;
+
+		/// Items carrying every tag in `include` and none in `exclude`, such as "jazz and vinyl
+		/// but not used". Scans up to `limit` items of the included tag with the fewest items,
+		/// starting after `cursor`, so a page can have fewer matches than `limit`, or none.
+		/// Returns the matches and the cursor of the next page, `None` once every item was
+		/// scanned. `None` if `include` is empty or a list has more tags than the runtime allows.
+		fn items_with_all_tags(
+			include: Vec<u64>,
+			exclude: Vec<u64>,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Option<(Vec<(CollectionId, ItemId)>, Option<(CollectionId, ItemId)>)>;
+
+		/// Items carrying any tag in `include` and none in `exclude`, paged like
+		/// `items_with_all_tags`. Each item is returned once, whatever tags it matched.
+		fn items_with_any_tag(
+			include: Vec<u64>,
+			exclude: Vec<u64>,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Option<(Vec<(CollectionId, ItemId)>, Option<(CollectionId, ItemId)>)>;
+	}
+}
@@ -6,7 +6,9 @@ use super::*;
 use crate::Pallet as Tags;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
-use frame_support::traits::{Get, Currency};
+use frame_support::traits::{Currency, EnsureOrigin, Get, Task as _};
+use codec::Encode;
+use sp_runtime::traits::{Bounded, Hash, IdentifyAccount};
 
 macro_rules! bvec {
 	($( $x:tt )*) => {
@@ -21,12 +23,60 @@ mod benchmarks {
 	use sp_core::bounded::BoundedVec;
 	use sp_std::vec;
 
+	/// Fills a tag with the maximum amount of translations, each of the maximum length.
+	fn add_translations<T: Config>(owner: &T::AccountId, tag_index: u64) {
+		for i in 0..T::MaxTranslations::get() {
+			let lang: BoundedVec<u8, T::LanguageCodeLimit> = i.encode().try_into().unwrap();
+			let text = bvec![0u8; T::TranslationLimit::get() as usize];
+			Tags::<T>::set_tag_translation(
+				RawOrigin::Signed(owner.clone()).into(), tag_index, lang, text,
+			).unwrap();
+		}
+	}
+
+	/// Mints the tags created from now on as NFTs, which makes creating, selling and removing
+	/// them heavier.
+	fn mint_tags_as_nfts<T: Config>() {
+		let Ok(origin) = T::ForceOrigin::try_successful_origin() else { return };
+		T::Currency::make_free_balance_be(&Tags::<T>::account_id(), BalanceOf::<T>::max_value() / 2u32.into());
+		Tags::<T>::create_tag_collection(origin).unwrap();
+	}
+
+	/// The smallest amount that can be staked.
+	fn min_stake<T: Config>() -> BalanceOf<T> {
+		T::MinStake::get().max(1u32.into())
+	}
+
+	/// Creates a tag charging the maximum usage fee and an item owned by `owner`, and returns
+	/// them if the runtime requires the approval of the owner of the item to apply the tag.
+	fn proposal_setup<T: Config>(
+		caller: &T::AccountId,
+		owner: &T::AccountId,
+	) -> Result<(u64, T::CollectionId, T::ItemId), BenchmarkError> {
+		let curator: T::AccountId = account("curator", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(owner, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&curator, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let (collection, item) = T::BenchmarkHelper::create_item(owner);
+		if T::TagPermission::policy(tag_index, &collection) != TaggingPolicy::ItemOwnerApproval {
+			return Err(BenchmarkError::Weightless);
+		}
+		Tags::<T>::create_tag(RawOrigin::Signed(curator.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::set_usage_fee(RawOrigin::Signed(curator).into(), tag_index, Some(T::MaxUsageFee::get())).unwrap();
+
+		Ok((tag_index, collection, item))
+	}
+
 	#[benchmark]
-	fn create_tag() {
-		let caller: T::AccountId = whitelisted_caller();
+	fn create_tag(n: Linear<0, { T::TagNameLimit::get() }>) {
+		mint_tags_as_nfts::<T>();
+		let caller: T::AccountId = account("caller", 0, 0);
 		let tag_index = TagIndex::<T>::get();
 		let deposit = T::TagDepositAmount::get();
-		let name = bvec![];
+		let name = bvec![0u8; n as usize];
 
 		T::Currency::make_free_balance_be(&caller, deposit + deposit);
 
@@ -40,17 +90,19 @@ mod benchmarks {
 
 	#[benchmark]
 	fn destroy_tag() {
-		let caller: T::AccountId = whitelisted_caller();
+		mint_tags_as_nfts::<T>();
+		let caller: T::AccountId = account("caller", 0, 0);
 		let tag_index = TagIndex::<T>::get();
 		let deposit = T::TagDepositAmount::get();
 		let name = bvec![];
 
-		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 
 		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), name.clone()).unwrap();
 		assert_eq!(TagMap::<T>::try_get(tag_index), Ok(
 			(name.clone(), caller.clone(), deposit)
 		));
+		add_translations::<T>(&caller, tag_index);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), tag_index);
@@ -58,5 +110,876 @@ mod benchmarks {
 		assert_eq!(TagMap::<T>::get(tag_index), None);
 	}
 
+	#[benchmark]
+	fn report_tag(r: Linear<0, { T::ReportReasonLimit::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let reporter: T::AccountId = account("reporter", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+		let bond = T::ReportBond::get();
+		let reason = bvec![0u8; r as usize];
+
+		T::Currency::make_free_balance_be(&owner, deposit + deposit);
+		T::Currency::make_free_balance_be(&reporter, bond + bond);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(owner).into(), bvec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(reporter.clone()), tag_index, reason.clone());
+
+		assert_eq!(Reports::<T>::get(tag_index), Some((reporter, reason, bond)));
+	}
+
+	#[benchmark]
+	fn uphold_report() -> Result<(), BenchmarkError> {
+		mint_tags_as_nfts::<T>();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let reporter: T::AccountId = account("reporter", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let bond = T::ReportBond::get();
+
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&reporter, bond + bond);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(owner.clone()).into(), bvec![]).unwrap();
+		add_translations::<T>(&owner, tag_index);
+		Tags::<T>::report_tag(RawOrigin::Signed(reporter).into(), tag_index, bvec![]).unwrap();
+
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, tag_index);
+
+		assert_eq!(TagMap::<T>::get(tag_index), None);
+		assert_eq!(Reports::<T>::get(tag_index), None);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dismiss_report() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let reporter: T::AccountId = account("reporter", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+		let bond = T::ReportBond::get();
+
+		T::Currency::make_free_balance_be(&owner, deposit + deposit);
+		T::Currency::make_free_balance_be(&reporter, bond + bond);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(owner).into(), bvec![]).unwrap();
+		Tags::<T>::report_tag(RawOrigin::Signed(reporter).into(), tag_index, bvec![]).unwrap();
+
+		let origin =
+			T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, tag_index);
+
+		assert!(TagMap::<T>::contains_key(tag_index));
+		assert_eq!(Reports::<T>::get(tag_index), None);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn tag_account() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let target: T::AccountId = account("target", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, target.clone());
+
+		assert!(AccountTags::<T>::contains_key(&target, tag_index));
+	}
+
+	#[benchmark]
+	fn untag_account() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let target: T::AccountId = account("target", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::tag_account(RawOrigin::Signed(caller.clone()).into(), tag_index, target.clone()).unwrap();
+
+		// The owner of the tag goes through the extra ownership check.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, target.clone());
+
+		assert!(!AccountTags::<T>::contains_key(&target, tag_index));
+	}
+
+	#[benchmark]
+	fn create_tag_with_signature(n: Linear<0, { T::TagNameLimit::get() }>) {
+		mint_tags_as_nfts::<T>();
+		let relayer: T::AccountId = account("relayer", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&relayer, deposit + deposit);
+
+		// The relayer paying the deposit is the worst case, it needs an extra write.
+		let payload: PreSignedTagOf<T> = PreSignedTag {
+			name: bvec![0u8; n as usize],
+			deadline: frame_system::Pallet::<T>::block_number(),
+			nonce: 0,
+			signer_pays_deposit: false,
+		};
+		let public = T::BenchmarkHelper::create_signer();
+		let signature = T::BenchmarkHelper::sign(&public, &Tags::<T>::signed_message(&payload));
+		let signer: T::AccountId = public.into_account();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer.clone()), payload, signer.clone(), signature);
+
+		assert_eq!(TagMap::<T>::get(tag_index).map(|(_, owner, _)| owner), Some(signer.clone()));
+		assert_eq!(TagDepositors::<T>::get(tag_index), Some(relayer));
+		assert_eq!(SignerNonces::<T>::get(&signer), 1);
+	}
+
+	#[benchmark]
+	fn list_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, deposit);
+
+		assert_eq!(Listings::<T>::get(tag_index), Some(deposit));
+	}
+
+	#[benchmark]
+	fn buy_tag() {
+		mint_tags_as_nfts::<T>();
+		let seller: T::AccountId = account("seller", 0, 0);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+		let price = deposit;
+
+		T::Currency::make_free_balance_be(&sponsor, deposit + deposit);
+		T::Currency::make_free_balance_be(&seller, deposit + deposit);
+		T::Currency::make_free_balance_be(&buyer, deposit + deposit + price + price);
+
+		// The worst case is a deposit paid by another account, which is one more account to touch.
+		Tags::<T>::do_create_tag(seller.clone(), bvec![], sponsor).unwrap();
+		Tags::<T>::list_tag(RawOrigin::Signed(seller).into(), tag_index, price).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), tag_index, price);
+
+		assert_eq!(TagMap::<T>::get(tag_index).map(|(_, owner, _)| owner), Some(buyer));
+		assert_eq!(TagDepositors::<T>::get(tag_index), None);
+		assert_eq!(Listings::<T>::get(tag_index), None);
+	}
+
+	#[benchmark]
+	fn delist_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::list_tag(RawOrigin::Signed(caller.clone()).into(), tag_index, deposit).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index);
+
+		assert_eq!(Listings::<T>::get(tag_index), None);
+	}
+
+	#[benchmark]
+	fn commit_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let deposit = T::CommitmentDeposit::get();
+		let hash = T::Hashing::hash_of(&0u64);
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), hash);
+
+		assert!(Commitments::<T>::contains_key(hash));
+	}
+
+	#[benchmark]
+	fn reveal_tag(n: Linear<0, { T::TagNameLimit::get() }>) {
+		mint_tags_as_nfts::<T>();
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+		let commitment_deposit = T::CommitmentDeposit::get();
+		let name: BoundedVec<u8, T::TagNameLimit> = bvec![0u8; n as usize];
+		let salt = [0u8; 32];
+		let hash = T::Hashing::hash_of(&(&caller, &name, &salt));
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit + commitment_deposit);
+
+		Tags::<T>::commit_tag(RawOrigin::Signed(caller.clone()).into(), hash).unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::MinCommitmentAge::get(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), salt);
+
+		assert_eq!(TagNames::<T>::get(&name), Some(tag_index));
+		assert!(!Commitments::<T>::contains_key(hash));
+	}
+
+	#[benchmark]
+	fn clear_commitment() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let deposit = T::CommitmentDeposit::get();
+		let hash = T::Hashing::hash_of(&0u64);
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::commit_tag(RawOrigin::Signed(caller.clone()).into(), hash).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), hash);
+
+		assert!(!Commitments::<T>::contains_key(hash));
+	}
+
+	#[benchmark]
+	fn set_tag_translation(
+		l: Linear<0, { T::LanguageCodeLimit::get() }>,
+		t: Linear<0, { T::TranslationLimit::get() }>,
+	) {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let previous: T::AccountId = account("previous", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let lang: BoundedVec<u8, T::LanguageCodeLimit> = bvec![0u8; l as usize];
+		let text: BoundedVec<u8, T::TranslationLimit> = bvec![0u8; t as usize];
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+
+		// Replacing a translation paid by a previous owner of the tag is the worst case,
+		// it refunds a second account.
+		T::Currency::make_free_balance_be(&previous, T::Currency::minimum_balance());
+		TagTranslations::<T>::insert(tag_index, &lang, (text.clone(), previous, BalanceOf::<T>::default()));
+		TranslationCounts::<T>::insert(tag_index, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), tag_index, lang.clone(), text.clone());
+
+		assert_eq!(
+			TagTranslations::<T>::get(tag_index, &lang).map(|(_, payer, _)| payer),
+			Some(caller),
+		);
+	}
+
+	#[benchmark]
+	fn clear_tag_translation() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let lang: BoundedVec<u8, T::LanguageCodeLimit> = bvec![0u8; T::LanguageCodeLimit::get() as usize];
+		let text = bvec![0u8; T::TranslationLimit::get() as usize];
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::set_tag_translation(
+			RawOrigin::Signed(caller.clone()).into(), tag_index, lang.clone(), text,
+		).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, lang.clone());
+
+		assert!(!TagTranslations::<T>::contains_key(tag_index, &lang));
+		assert_eq!(TranslationCounts::<T>::get(tag_index), 0);
+	}
+
+	#[benchmark]
+	fn start_destroy_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index);
+
+		assert_eq!(TagStatuses::<T>::get(tag_index), TagStatus::Destroying);
+	}
+
+	#[benchmark]
+	fn destroy_tag_references(n: Linear<0, { T::RemoveReferencesLimit::get() }>) {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		// Item references are heavier than account references, they update the item count.
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		for _ in 0..n {
+			let (collection, item) = T::BenchmarkHelper::create_item(&caller);
+			Tags::<T>::tag_item(
+				RawOrigin::Signed(caller.clone()).into(), tag_index, collection, item, T::MaxUsageFee::get(),
+			).unwrap();
+		}
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller.clone()).into(), tag_index).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, n);
+
+		assert_eq!(TagReferences::<T>::get(tag_index), 0);
+	}
+
+	#[benchmark]
+	fn finish_destroy_tag() {
+		mint_tags_as_nfts::<T>();
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		add_translations::<T>(&caller, tag_index);
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller.clone()).into(), tag_index).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index);
+
+		assert_eq!(TagMap::<T>::get(tag_index), None);
+		assert_eq!(TagStatuses::<T>::get(tag_index), TagStatus::Live);
+	}
+
+	#[benchmark]
+	fn purge_tag_reference() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let target: T::AccountId = account("target", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let deposit = T::TagDepositAmount::get();
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::tag_account(RawOrigin::Signed(caller.clone()).into(), tag_index, target.clone()).unwrap();
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller).into(), tag_index).unwrap();
+
+		let task = Task::<T>::purge_tag_reference { tag_index, account: target.clone() };
+
+		// The condition is checked before running the task.
+		#[block]
+		{
+			assert!(task.is_valid());
+			task.run().unwrap();
+		}
+
+		assert!(!AccountTags::<T>::contains_key(&target, tag_index));
+	}
+
+	#[benchmark]
+	fn finish_tag_destruction() {
+		mint_tags_as_nfts::<T>();
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		add_translations::<T>(&caller, tag_index);
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller).into(), tag_index).unwrap();
+
+		let task = Task::<T>::finish_tag_destruction { tag_index };
+
+		#[block]
+		{
+			assert!(task.is_valid());
+			task.run().unwrap();
+		}
+
+		assert_eq!(TagMap::<T>::get(tag_index), None);
+	}
+
+	#[benchmark]
+	fn clear_expired_commitment() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let deposit = T::CommitmentDeposit::get();
+		let hash = T::Hashing::hash_of(&0u64);
+
+		T::Currency::make_free_balance_be(&caller, deposit + deposit);
+
+		Tags::<T>::commit_tag(RawOrigin::Signed(caller).into(), hash).unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::MaxCommitmentAge::get() + 1u32.into(),
+		);
+
+		let task = Task::<T>::clear_expired_commitment { hash };
+
+		#[block]
+		{
+			assert!(task.is_valid());
+			task.run().unwrap();
+		}
+
+		assert!(!Commitments::<T>::contains_key(hash));
+	}
+
+	#[benchmark]
+	fn tag_item() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let curator: T::AccountId = account("curator", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&curator, BalanceOf::<T>::max_value() / 2u32.into());
+
+		// Owning the item, the caller can apply tags under any policy but `TagOwner`. Paying the
+		// usage fee to the owner of the tag is the worst case.
+		let (collection, item) = T::BenchmarkHelper::create_item(&caller);
+		let owner = match T::TagPermission::policy(tag_index, &collection) {
+			TaggingPolicy::TagOwner => caller.clone(),
+			_ => curator,
+		};
+		Tags::<T>::create_tag(RawOrigin::Signed(owner.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::set_usage_fee(RawOrigin::Signed(owner).into(), tag_index, Some(T::MaxUsageFee::get())).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collection, item, T::MaxUsageFee::get());
+
+		assert!(TagsByItem::<T>::contains_key((collection, item), tag_index));
+	}
+
+	#[benchmark]
+	fn untag_item() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let tagger: T::AccountId = account("tagger", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&tagger, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let (collection, item) = T::BenchmarkHelper::create_item(&owner);
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::do_tag_item(tag_index, (collection, item), &tagger, T::MaxUsageFee::get()).unwrap();
+
+		// The owner of the tag goes through the extra ownership checks, and refunds another account.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collection, item);
+
+		assert!(!TagsByItem::<T>::contains_key((collection, item), tag_index));
+	}
+
+	#[benchmark]
+	fn purge_item_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let (collection, item) = T::BenchmarkHelper::create_item(&caller);
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::tag_item(
+			RawOrigin::Signed(caller.clone()).into(), tag_index, collection, item, T::MaxUsageFee::get(),
+		).unwrap();
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller).into(), tag_index).unwrap();
+
+		let task = Task::<T>::purge_item_tag { tag_index, item: (collection, item) };
+
+		#[block]
+		{
+			assert!(task.is_valid());
+			task.run().unwrap();
+		}
+
+		assert!(!TagsByItem::<T>::contains_key((collection, item), tag_index));
+	}
+
+	#[benchmark]
+	fn backfill_attributes(n: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		if !T::MirrorToAttributes::get() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![0u8; T::TagNameLimit::get() as usize]).unwrap();
+		for _ in 0..n {
+			let (collection, item) = T::BenchmarkHelper::create_item(&caller);
+			Tags::<T>::tag_item(
+				RawOrigin::Signed(caller.clone()).into(), tag_index, collection, item, T::MaxUsageFee::get(),
+			).unwrap();
+		}
+
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, n);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn tag_collection() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let (collection, _item) = T::BenchmarkHelper::create_item(&owner);
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collection);
+
+		assert!(TagsByCollection::<T>::contains_key(collection, tag_index));
+	}
+
+	#[benchmark]
+	fn untag_collection() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let (collection, _item) = T::BenchmarkHelper::create_item(&owner);
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::tag_collection(RawOrigin::Signed(caller.clone()).into(), tag_index, collection).unwrap();
+
+		// The owner of the tag goes through the extra ownership check.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collection);
+
+		assert!(!TagsByCollection::<T>::contains_key(collection, tag_index));
+	}
+
+	#[benchmark]
+	fn purge_collection_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let (collection, _item) = T::BenchmarkHelper::create_item(&caller);
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::tag_collection(RawOrigin::Signed(caller.clone()).into(), tag_index, collection).unwrap();
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller).into(), tag_index).unwrap();
+
+		let task = Task::<T>::purge_collection_tag { tag_index, collection };
+
+		#[block]
+		{
+			assert!(task.is_valid());
+			task.run().unwrap();
+		}
+
+		assert!(!TagsByCollection::<T>::contains_key(collection, tag_index));
+	}
+
+	#[benchmark]
+	fn propose_item_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (tag_index, collection, item) = proposal_setup::<T>(&caller, &owner)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collection, item, T::MaxUsageFee::get());
+
+		assert!(TagProposals::<T>::contains_key((collection, item), tag_index));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_item_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (tag_index, collection, item) = proposal_setup::<T>(&caller, &owner)?;
+		Tags::<T>::propose_item_tag(
+			RawOrigin::Signed(caller).into(), tag_index, collection, item, T::MaxUsageFee::get(),
+		).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), tag_index, collection, item);
+
+		assert!(TagsByItem::<T>::contains_key((collection, item), tag_index));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_item_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (tag_index, collection, item) = proposal_setup::<T>(&caller, &owner)?;
+		Tags::<T>::propose_item_tag(
+			RawOrigin::Signed(caller).into(), tag_index, collection, item, T::MaxUsageFee::get(),
+		).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), tag_index, collection, item);
+
+		assert!(!TagProposals::<T>::contains_key((collection, item), tag_index));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_item_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let owner: T::AccountId = account("owner", 0, 0);
+		let (tag_index, collection, item) = proposal_setup::<T>(&caller, &owner)?;
+		Tags::<T>::propose_item_tag(
+			RawOrigin::Signed(caller.clone()).into(), tag_index, collection, item, T::MaxUsageFee::get(),
+		).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collection, item);
+
+		assert!(!TagProposals::<T>::contains_key((collection, item), tag_index));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_usage_fee() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, Some(T::MaxUsageFee::get()));
+
+		assert_eq!(UsageFees::<T>::get(tag_index), Some(T::MaxUsageFee::get()));
+	}
+
+	#[benchmark]
+	fn set_tag_scope() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		// Only the length of the scope matters, so it repeats a single collection.
+		let (collection, _item) = T::BenchmarkHelper::create_item(&caller);
+		let collections: BoundedVec<_, _> =
+			bvec![collection; T::MaxScopeCollections::get() as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index, collections.clone());
+
+		assert_eq!(TagScopes::<T>::get(tag_index), Some(collections));
+	}
+
+	#[benchmark]
+	fn stake_on_tag() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let staker: T::AccountId = account("staker", 0, 0);
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let tag_index = TagIndex::<T>::get();
+		Tags::<T>::create_tag(RawOrigin::Signed(caller).into(), bvec![]).unwrap();
+		let amount = min_stake::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(staker.clone()), tag_index, amount);
+
+		assert_eq!(Stakes::<T>::get(tag_index, &staker), Some(amount));
+	}
+
+	#[benchmark]
+	fn unstake() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let chunks = T::MaxUnbondingChunks::get();
+		let amount = min_stake::<T>();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let tag_index = TagIndex::<T>::get();
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		let stake = amount.saturating_mul((chunks + 1).into());
+		Tags::<T>::stake_on_tag(RawOrigin::Signed(caller.clone()).into(), tag_index, stake).unwrap();
+		for _ in 1..chunks {
+			Tags::<T>::unstake(RawOrigin::Signed(caller.clone()).into(), tag_index, amount).unwrap();
+		}
+
+		// Filling the last unbonding chunk is the heaviest.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), tag_index, amount);
+
+		assert_eq!(Stakes::<T>::get(tag_index, &caller), Some(amount));
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+		let chunks = T::MaxUnbondingChunks::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let amount = min_stake::<T>();
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		let stake = amount.saturating_mul(chunks.into());
+		Tags::<T>::stake_on_tag(RawOrigin::Signed(caller.clone()).into(), tag_index, stake).unwrap();
+		for _ in 0..chunks {
+			Tags::<T>::unstake(RawOrigin::Signed(caller.clone()).into(), tag_index, amount).unwrap();
+		}
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::StakeUnbondingPeriod::get(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Unbonding::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn purge_stake() {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let staker: T::AccountId = account("staker", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&staker, BalanceOf::<T>::max_value() / 2u32.into());
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![]).unwrap();
+		Tags::<T>::stake_on_tag(RawOrigin::Signed(staker.clone()).into(), tag_index, min_stake::<T>()).unwrap();
+		Tags::<T>::start_destroy_tag(RawOrigin::Signed(caller).into(), tag_index).unwrap();
+
+		let task = Task::<T>::purge_stake { tag_index, staker: staker.clone() };
+
+		// The condition is checked before running the task.
+		#[block]
+		{
+			assert!(task.is_valid());
+			task.run().unwrap();
+		}
+
+		assert_eq!(Stakes::<T>::get(tag_index, &staker), None);
+	}
+
+	#[benchmark]
+	fn merge_tags(n: Linear<0, { T::RemoveReferencesLimit::get() }>) {
+		mint_tags_as_nfts::<T>();
+		let caller: T::AccountId = account("caller", 0, 0);
+		let from = TagIndex::<T>::get();
+		let into = from + 1;
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		// Moving item references is the heaviest, and the merge finishes in the same call.
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![0u8]).unwrap();
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![1u8]).unwrap();
+		add_translations::<T>(&caller, from);
+		for _ in 0..n {
+			let (collection, item) = T::BenchmarkHelper::create_item(&caller);
+			Tags::<T>::tag_item(
+				RawOrigin::Signed(caller.clone()).into(), from, collection, item, T::MaxUsageFee::get(),
+			).unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), from, into, n);
+
+		assert_eq!(TagAliases::<T>::get(from), Some(into));
+		assert_eq!(TagReferences::<T>::get(into), n);
+	}
+
+	#[benchmark]
+	fn create_tag_collection() -> Result<(), BenchmarkError> {
+		T::Currency::make_free_balance_be(&Tags::<T>::account_id(), BalanceOf::<T>::max_value() / 2u32.into());
+
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(TagCollection::<T>::exists());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn sync_tag_owner() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let holder: T::AccountId = account("holder", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		T::Currency::make_free_balance_be(&Tags::<T>::account_id(), BalanceOf::<T>::max_value() / 2u32.into());
+		Tags::<T>::create_tag_collection(origin).unwrap();
+
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![0u8]).unwrap();
+		Tags::<T>::list_tag(RawOrigin::Signed(caller.clone()).into(), tag_index, 1u32.into()).unwrap();
+		let collection = TagCollection::<T>::get().unwrap();
+		let item = T::ItemId::try_from(tag_index).map_err(|_| BenchmarkError::Weightless)?;
+		T::Nfts::transfer(&collection, &item, &holder).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index);
+
+		assert_eq!(TagMap::<T>::get(tag_index).map(|(_, owner, _)| owner), Some(holder));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_burned_tag() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, 0);
+		let tag_index = TagIndex::<T>::get();
+
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let origin = T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		T::Currency::make_free_balance_be(&Tags::<T>::account_id(), BalanceOf::<T>::max_value() / 2u32.into());
+		Tags::<T>::create_tag_collection(origin).unwrap();
+
+		// Destroying the tag at once, with all its translations, is the heaviest.
+		Tags::<T>::create_tag(RawOrigin::Signed(caller.clone()).into(), bvec![0u8]).unwrap();
+		add_translations::<T>(&caller, tag_index);
+		let collection = TagCollection::<T>::get().unwrap();
+		let item = T::ItemId::try_from(tag_index).map_err(|_| BenchmarkError::Weightless)?;
+		T::Nfts::burn(&collection, &item, None).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), tag_index);
+
+		assert_eq!(TagMap::<T>::get(tag_index), None);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
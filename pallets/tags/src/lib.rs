@@ -1,9 +1,80 @@
 //! # Tags Pallet
 //!
 //! This pallet implements a basic tag system, where accounts reserve some amount of their funds
-//! for the creation of a tag, that has an associated name.
+//! for the creation of a tag, that has an associated name. Names are unique.
 //!
-//! This pallet is intended to be used along with nfts, but the support is not yet implemented.
+//! Names submitted in plain text with `create_tag` can be front-run by anyone watching the
+//! transaction pool. To avoid it, an account first commits to the hash of the name with
+//! `commit_tag`, and reveals it with `reveal_tag` after a minimum delay. Runtimes that want every
+//! name to be protected can use [`RevealedNamesOnly`] as their call filter, which rejects
+//! `create_tag` and `create_tag_with_signature`.
+//!
+//! Tags can be applied to NFTs, or by their owner to whole collections whose items all inherit it.
+//! Both directions are indexed, so the items carrying a tag and the tags of an item can be listed
+//! page by page through the runtime API, which also merges the tags an item inherits from its
+//! collection, and finds the items carrying all or any of some tags but none of others.
+//! Runtimes can also mirror the tags of an item into its attributes, in the namespace of the
+//! pallet, so wallets that only know about NFT attributes can show them. The runtime
+//! decides who can apply a tag to an item: anyone, the owner of the tag, or anyone with the
+//! approval of the owner of the item, who accepts or rejects proposals backed by a deposit.
+//! Applying a tag to an item reserves a deposit from the tagger, returned when the tag is removed.
+//! Owners can also charge others a usage fee for applying their tags, and clients can quote the
+//! total cost of tagging through the runtime API. Brand tags can be restricted to the
+//! collections of the brand with `set_tag_scope`.
+//!
+//! The pallet records when each tag was created, last changed and last applied, as block numbers
+//! with the timestamps of `pallet_timestamp`. Clients get them along with the rest of the tag
+//! through the runtime API, and the related events carry them too.
+//!
+//! Tags can also be created by a relayer on behalf of an account that signed the creation data,
+//! so accounts without funds for the transaction fees can still create tags.
+//!
+//! Owners can give their tags display names in other languages, paying a deposit per byte.
+//! Clients fetch them through the runtime API, falling back to the canonical name.
+//!
+//! Owners can list their tags for sale. A purchase pays the seller and moves the deposit
+//! obligation to the buyer.
+//!
+//! The owner of a tag can apply it to accounts, so tags can work as on-chain roles. Other pallets
+//! can require a role with the [`EnsureTagged`] origin.
+//!
+//! Tags in use can't be destroyed at once. Instead, the owner starts destroying the tag with
+//! `start_destroy_tag`, anyone removes its references over several blocks with
+//! `destroy_tag_references`, and `finish_destroy_tag` removes the tag and returns its deposit.
+//!
+//! Duplicated tags can be merged. The references of one tag are moved to the other over several
+//! blocks with `merge_tags`, then the tag is removed, leaving an alias so its id and name still
+//! resolve to the other tag.
+//!
+//! Ids are never reused. Removed tags leave a tombstone recording who removed them, when, and
+//! the tag they were merged into, so calls using their id fail with `TagDestroyedAlready` instead
+//! of `InvalidTag`, and clients can look them up through the runtime API.
+//!
+//! Services that don't trust the node they query can ask it for a proof of the name and owner of
+//! a tag at some block, and check it against a header they trust with the `pallet-tags-proof`
+//! crate, which also works in `no_std` environments such as bridges.
+//!
+//! Once `create_tag_collection` has created a collection owned by the pallet, new tags are also
+//! minted as items of it, so wallets and marketplaces can show and transfer them. The owner of
+//! the item is then the owner of the tag. `pallet_nfts` has no transfer hooks, so after a transfer
+//! made outside this pallet anyone can call `sync_tag_owner` to update the stored owner, drop its
+//! listing and emit `TagTransferred`. Until then the stored owner is only a cache, and so is the
+//! owner attested by proofs. A tag whose item was burned has no owner, and anyone can remove it
+//! with `remove_burned_tag`, returning its deposit to the account that paid it.
+//!
+//! Any account can stake funds on a tag to signal that it matters, and clients rank tags by their
+//! total stake through the runtime API. Unstaked funds stay reserved for an unbonding period
+//! before they can be withdrawn. Stakes don't keep a tag from being destroyed, they go back to
+//! their stakers without unbonding once it's destroyed, merged or removed by a moderator.
+//!
+//! Cleanup work is also exposed as tasks: purging references to removed tags and burned items,
+//! returning stakes on them, finishing destructions and clearing expired commitments. Any account
+//! or offchain worker can submit them as unsigned `do_task` extrinsics, which are only valid while
+//! there is work to do.
+//!
+//! Any account can report a tag by reserving a small bond. A moderator origin then either upholds
+//! the report, removing the tag and slashing its deposit, or dismisses it, slashing the bond of the
+//! reporter instead.
 
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -29,16 +100,41 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Extension points for other pallets.
+pub mod traits;
+pub use traits::*;
+
+mod types;
+pub use types::*;
+
+use core::marker::PhantomData;
+use frame_support::traits::{Contains, EnsureOrigin, Get, IsSubType};
+use frame_system::RawOrigin;
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
 	// Import various useful types required by all FRAME pallets.
 	use super::*;
-	use frame_support::{pallet_prelude::*, traits::{Currency, ReservableCurrency}};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			tokens::nonfungibles_v2::{Create, Inspect, Mutate, Transfer},
+			Currency, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency, Time,
+		},
+		PalletId, StorageHasher,
+	};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{
+		AccountIdConversion, Hash as HashT, IdentifyAccount, Saturating, Verify, Zero,
+	};
+	use sp_std::vec::Vec;
 
-	type BalanceOf<T> =
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
@@ -62,13 +158,169 @@ pub mod pallet {
 		/// The deposit necessary to create a tag
 		#[pallet::constant]
 		type TagDepositAmount: Get<BalanceOf<Self>>;
+
+		/// The origin allowed to uphold or dismiss tag reports.
+		type ModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to run migrations, and to merge tags of any owner.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Where the funds slashed by moderation go, for example a treasury.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The bond reserved from an account when it reports a tag.
+		#[pallet::constant]
+		type ReportBond: Get<BalanceOf<Self>>;
+
+		/// The maximum length of the reason given in a report.
+		#[pallet::constant]
+		type ReportReasonLimit: Get<u32>;
+
+		/// Hooks called when tags are created, destroyed or change hands.
+		type OnTagLifecycle: OnTagLifecycle<Self::AccountId>;
+
+		/// Off-chain signature used for pre-signed tag creation.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key that verifies off-chain signatures, identifying an account.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The deposit reserved while a commitment to a tag name is pending.
+		#[pallet::constant]
+		type CommitmentDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of blocks a commitment must wait before it can be revealed.
+		#[pallet::constant]
+		type MinCommitmentAge: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks after which a commitment expires and can't be revealed anymore.
+		#[pallet::constant]
+		type MaxCommitmentAge: Get<BlockNumberFor<Self>>;
+
+		/// The maximum length of a language code, such as `pt-BR`.
+		#[pallet::constant]
+		type LanguageCodeLimit: Get<u32>;
+
+		/// The maximum length of a translated display name.
+		#[pallet::constant]
+		type TranslationLimit: Get<u32>;
+
+		/// The maximum number of languages a tag can be translated to.
+		#[pallet::constant]
+		type MaxTranslations: Get<u32>;
+
+		/// The deposit reserved per byte of language code and translated name.
+		#[pallet::constant]
+		type TranslationDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum number of references removed by a single `destroy_tag_references` call.
+		#[pallet::constant]
+		type RemoveReferencesLimit: Get<u32>;
+
+		/// Identifier of a collection of NFTs.
+		type CollectionId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an NFT within its collection.
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy + TryFrom<u64>;
+
+		/// The NFTs tags are applied to.
+		type Nfts: Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
+			+ Mutate<Self::AccountId, Self::ItemConfig>
+			+ Transfer<Self::AccountId>
+			+ Create<Self::AccountId, Self::CollectionConfig>;
+
+		/// The configuration of NFT items.
+		type ItemConfig: Default;
+
+		/// The configuration of NFT collections, the default one is used for the collection of
+		/// tags.
+		type CollectionConfig: Default;
+
+		/// Whether tags applied to items are also written to the attributes of the items, in the
+		/// namespace of the pallet, so wallets that only know about NFT attributes see them.
+		#[pallet::constant]
+		type MirrorToAttributes: Get<bool>;
+
+		/// The maximum number of tags applied to a single item, or to a single collection.
+		#[pallet::constant]
+		type MaxTagsPerItem: Get<u32>;
+
+		/// Decides who can apply a tag to the items of a collection.
+		type TagPermission: TagPermission<Self::CollectionId>;
+
+		/// The deposit reserved from an account proposing a tag for an item, when the owner of
+		/// the item must approve it. It's returned if the proposal is accepted or withdrawn, and
+		/// slashed if it's rejected.
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved from the account applying a tag to an item, for the index storage.
+		/// It's returned to that account when the tag is removed.
+		#[pallet::constant]
+		type TaggingDeposit: Get<BalanceOf<Self>>;
+
+		/// Whether the tagging deposit is slashed to `Slash`, instead of returned, when the owner
+		/// of an item removes a tag applied by someone else.
+		#[pallet::constant]
+		type SlashRemovedTaggings: Get<bool>;
+
+		/// The maximum fee the owner of a tag can charge others for applying it to an item.
+		#[pallet::constant]
+		type MaxUsageFee: Get<BalanceOf<Self>>;
+
+		/// The source of the timestamps recorded with the activity of tags, usually
+		/// `pallet_timestamp`.
+		type Time: Time;
+
+		/// The maximum number of tags to include or exclude in a single item query.
+		#[pallet::constant]
+		type MaxQueryTags: Get<u32>;
+
+		/// The id of the pallet, whose account owns the collection tags are minted in.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of collections in the scope of a tag.
+		#[pallet::constant]
+		type MaxScopeCollections: Get<u32>;
+
+		/// The number of blocks unstaked funds stay reserved before they can be withdrawn.
+		#[pallet::constant]
+		type StakeUnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of unstaked amounts an account can have unbonding at once.
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+
+		/// The minimum stake of an account on a tag.
+		#[pallet::constant]
+		type MinStake: Get<BalanceOf<Self>>;
+
+		/// Creates and signs pre-signed payloads, and creates items, for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::OffchainPublic,
+			Self::OffchainSignature,
+			Self::AccountId,
+			Self::CollectionId,
+			Self::ItemId,
+		>;
 	}
 
+	/// Prefix of the messages signed for `create_tag_with_signature`, so signatures made for
+	/// other pallets can't be used here.
+	pub const PRE_SIGNED_TAG_CONTEXT: &[u8] = b"pallet-tags/pre-signed-tag";
+
 	/// Counter of the next available index for a tag
 	#[pallet::storage]
 	pub type TagIndex<T> = StorageValue<_, u64, ValueQuery>;
 
 	/// Tags stored in the network
+	///
+	/// Once tags are NFTs, the owner of a tag is the holder of its item, and the owner stored here
+	/// is only a cache of it. `pallet_nfts` has no transfer hooks, so it lags behind transfers
+	/// made outside this pallet until `sync_tag_owner` is called, and proofs of this entry attest
+	/// the cached owner.
 	#[pallet::storage]
 	#[pallet::getter(fn tag_info)]
 	pub type TagMap<T: Config> = StorageMap<
@@ -82,58 +334,2077 @@ pub mod pallet {
 		QueryKind = OptionQuery
 	>;
 
+	/// Tag ids by name, which keeps names unique.
+	#[pallet::storage]
+	pub type TagNames<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = BoundedVec<u8, T::TagNameLimit>,
+		Value = u64,
+		QueryKind = OptionQuery
+	>;
+
+	/// When each tag was created, last changed and last applied to something.
+	#[pallet::storage]
+	pub type TagActivities<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = TagActivityOf<T>,
+		QueryKind = OptionQuery
+	>;
+
+	/// Who removed each destroyed tag, when, and the tag it was merged into, if any. Ids are
+	/// never reused, so clients holding one can tell why it's gone.
+	#[pallet::storage]
+	pub type Tombstones<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = TombstoneOf<T>,
+		QueryKind = OptionQuery
+	>;
+
+	/// The collection tags are minted in, each tag as the item with the same id. Until it's
+	/// created with `create_tag_collection`, tags aren't NFTs.
+	#[pallet::storage]
+	pub type TagCollection<T: Config> = StorageValue<_, T::CollectionId, OptionQuery>;
+
+	/// The first tag minted as an item of `TagCollection`. Every tag created since then is an NFT.
+	#[pallet::storage]
+	pub type FirstTagItem<T> = StorageValue<_, u64, OptionQuery>;
+
+	/// The tags that were merged into other tags, with the tag each one was merged into.
+	#[pallet::storage]
+	pub type TagAliases<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = u64,
+		QueryKind = OptionQuery
+	>;
+
+	/// Pending commitments to tag names, by hash of the committer, the name and a salt.
+	#[pallet::storage]
+	pub type Commitments<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = T::Hash,
+		Value = (
+			T::AccountId, // committer
+			BlockNumberFor<T>, // block of the commitment
+			BalanceOf<T>, // deposit
+		),
+		QueryKind = OptionQuery
+	>;
+
+	/// Display names of tags in other languages, by tag id and language code.
+	#[pallet::storage]
+	pub type TagTranslations<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = u64,
+		Hasher2 = Blake2_128Concat,
+		Key2 = BoundedVec<u8, T::LanguageCodeLimit>,
+		Value = (
+			BoundedVec<u8, T::TranslationLimit>, // display name
+			T::AccountId, // depositor
+			BalanceOf<T>, // deposit
+		),
+		QueryKind = OptionQuery
+	>;
+
+	/// Number of translations of each tag.
+	#[pallet::storage]
+	pub type TranslationCounts<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = u32,
+		QueryKind = ValueQuery
+	>;
+
+	/// Accounts that paid the deposit of a tag, when it wasn't its owner or when tags are NFTs.
+	#[pallet::storage]
+	pub type TagDepositors<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = T::AccountId,
+		QueryKind = OptionQuery
+	>;
+
+	/// Next nonce expected in a pre-signed tag from each account. Prevents replaying payloads.
+	#[pallet::storage]
+	pub type SignerNonces<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = T::AccountId,
+		Value = u64,
+		QueryKind = ValueQuery
+	>;
+
+	/// Tags listed for sale by their owners, with their price.
+	#[pallet::storage]
+	pub type Listings<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = BalanceOf<T>,
+		QueryKind = OptionQuery
+	>;
+
+	/// The fee paid to the owner of a tag by other accounts applying it to an item.
+	#[pallet::storage]
+	pub type UsageFees<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = BalanceOf<T>,
+		QueryKind = OptionQuery
+	>;
+
+	/// The collections a tag is restricted to, set by its owner. Tags without a scope can be
+	/// applied to any collection.
+	#[pallet::storage]
+	pub type TagScopes<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = BoundedVec<T::CollectionId, T::MaxScopeCollections>,
+		QueryKind = OptionQuery
+	>;
+
+	/// The funds each account staked on a tag, by tag id and account. Stakes aren't references
+	/// to the tag, those left on a tag that is gone are released by a task.
+	#[pallet::storage]
+	pub type Stakes<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = u64,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::AccountId,
+		Value = BalanceOf<T>,
+		QueryKind = OptionQuery
+	>;
+
+	/// The total funds staked on each tag.
+	#[pallet::storage]
+	pub type TotalStakes<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = BalanceOf<T>,
+		QueryKind = ValueQuery
+	>;
+
+	/// Funds each account unstaked, still reserved until the block they unlock at.
+	#[pallet::storage]
+	pub type Unbonding<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = T::AccountId,
+		Value = BoundedVec<(BalanceOf<T>, BlockNumberFor<T>), T::MaxUnbondingChunks>,
+		QueryKind = OptionQuery
+	>;
+
+	/// Tags applied to accounts, by account and tag id.
+	#[pallet::storage]
+	pub type AccountTags<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = T::AccountId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = u64,
+		Value = (),
+		QueryKind = OptionQuery
+	>;
+
+	/// Accounts each tag is applied to, by tag id and account. Mirrors `AccountTags`, so the
+	/// references of a tag can be removed when it's destroyed.
+	#[pallet::storage]
+	pub type AccountsByTag<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = u64,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::AccountId,
+		Value = (),
+		QueryKind = OptionQuery
+	>;
+
+	/// Items each tag is applied to, by tag id and item.
+	#[pallet::storage]
+	pub type ItemsByTag<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = u64,
+		Hasher2 = Blake2_128Concat,
+		Key2 = ItemOf<T>,
+		Value = (
+			T::AccountId, // tagger
+			BalanceOf<T>, // deposit
+		),
+		QueryKind = OptionQuery
+	>;
+
+	/// Tags applied to items, by item and tag id. Mirrors `ItemsByTag`.
+	#[pallet::storage]
+	pub type TagsByItem<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = ItemOf<T>,
+		Hasher2 = Blake2_128Concat,
+		Key2 = u64,
+		Value = (),
+		QueryKind = OptionQuery
+	>;
+
+	/// Number of tags applied to each item, bounded by `MaxTagsPerItem`.
+	#[pallet::storage]
+	pub type ItemTagCounts<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = ItemOf<T>,
+		Value = u32,
+		QueryKind = ValueQuery
+	>;
+
+	/// Number of items each tag is applied to. Item queries start from the tag with the fewest
+	/// items.
+	#[pallet::storage]
+	pub type TaggedItemCounts<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = u32,
+		QueryKind = ValueQuery
+	>;
+
+	/// Pending proposals to tag an item, by item and tag id, waiting for the owner of the item.
+	#[pallet::storage]
+	pub type TagProposals<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = ItemOf<T>,
+		Hasher2 = Blake2_128Concat,
+		Key2 = u64,
+		Value = (
+			T::AccountId, // proposer
+			BalanceOf<T>, // deposit
+			BalanceOf<T>, // max usage fee
+		),
+		QueryKind = OptionQuery
+	>;
+
+	/// Collections each tag is applied to, by tag id and collection. Their items inherit the tag.
+	#[pallet::storage]
+	pub type CollectionsByTag<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = u64,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::CollectionId,
+		Value = (),
+		QueryKind = OptionQuery
+	>;
+
+	/// Tags applied to collections, by collection and tag id. Mirrors `CollectionsByTag`.
+	#[pallet::storage]
+	pub type TagsByCollection<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = T::CollectionId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = u64,
+		Value = (),
+		QueryKind = OptionQuery
+	>;
+
+	/// Number of tags applied to each collection, bounded by `MaxTagsPerItem`.
+	#[pallet::storage]
+	pub type CollectionTagCounts<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = T::CollectionId,
+		Value = u32,
+		QueryKind = ValueQuery
+	>;
+
+	/// The last item tag whose attribute was written by `backfill_attributes`, while the backfill
+	/// is in progress.
+	#[pallet::storage]
+	pub type AttributeBackfill<T: Config> = StorageValue<_, (u64, ItemOf<T>), OptionQuery>;
+
+	/// Tags that are not live. Only tags being destroyed or merged are stored.
+	#[pallet::storage]
+	pub type TagStatuses<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = TagStatus,
+		QueryKind = ValueQuery
+	>;
+
+	/// Number of targets each tag is applied to. A tag can't be destroyed while it's in use.
+	#[pallet::storage]
+	pub type TagReferences<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = u32,
+		QueryKind = ValueQuery
+	>;
+
+	/// Pending reports against tags. A tag can only have one open report at a time.
+	#[pallet::storage]
+	pub type Reports<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = u64,
+		Value = (
+			T::AccountId, // reporter
+			BoundedVec<u8, T::ReportReasonLimit>, // reason
+			BalanceOf<T>, // bond
+		),
+		QueryKind = OptionQuery
+	>;
 
 	/// Events that functions in this pallet can emit.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A user has successfully set a new value.
+		/// A tag was created. Indexed by the topics of the tag and its owner.
 		TagCreated {
 			/// The tag id.
 			index: u64,
 			/// The account who created the tag.
 			who: T::AccountId,
+			/// The name of the tag.
+			name: BoundedVec<u8, T::TagNameLimit>,
 			/// The deposit reserved for the tag.
 			deposit: BalanceOf<T>,
+			/// When the tag was created.
+			created_at: BlockTimeOf<T>,
 		},
-		/// A user destroyed a previously created tag.
+		/// A user destroyed a previously created tag. Indexed by the topics of the tag and its
+		/// owner.
 		TagDestroyed {
 			/// The tag id.
 			index: u64,
 			/// The account that owned and destroyed the tag
 			who: T::AccountId,
+			/// The name the tag had.
+			name: BoundedVec<u8, T::TagNameLimit>,
 		},
-	}
-
-	/// Errors that can be returned by this pallet.
-	#[pallet::error]
-	pub enum Error<T> {
-		/// The value retrieved was `None` as no value was previously set.
-		NoneValue,
-		/// There are more tags than possible to store on chain (u64 id limit).
-		StorageOverflow,
-		/// The tag with the given ID doesn't exist in the network.
-		InvalidTag,
-		/// An user tried to modify a tag that it didn't create.
-		NotAllowed,
-	}
-
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Creates a tag with a name.
-		/// Tags are stored in TagMap, they contain a name, the creator, and the deposit reserved for them.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::create_tag())]
-		pub fn create_tag(origin: OriginFor<T>, name: BoundedVec<u8, T::TagNameLimit>) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			let who = ensure_signed(origin)?;
-
-			// Amount to deposit. Comes from configuration but it's good practice to store any amount
+		/// A relayer submitted a pre-signed tag. Follows the `TagCreated` event of the tag.
+		TagRelayed {
+			/// The tag id.
+			index: u64,
+			/// The account who signed the payload, and owns the tag.
+			signer: T::AccountId,
+			/// The account who submitted the payload.
+			relayer: T::AccountId,
+			/// The account the deposit was reserved from.
+			deposit_payer: T::AccountId,
+		},
+		/// An account committed to a tag name without revealing it.
+		TagCommitted {
+			/// The hash of the committer, the name and the salt.
+			hash: T::Hash,
+			/// The account who committed.
+			who: T::AccountId,
+		},
+		/// A committed name was revealed. Follows the `TagCreated` event of the tag.
+		TagRevealed {
+			/// The tag id.
+			index: u64,
+			/// The hash of the revealed commitment.
+			hash: T::Hash,
+		},
+		/// A commitment was removed without being revealed, and its deposit returned.
+		CommitmentCleared {
+			/// The hash of the commitment.
+			hash: T::Hash,
+			/// The account who committed.
+			who: T::AccountId,
+		},
+		/// The owner of a tag set its display name in a language.
+		TagTranslationSet {
+			/// The tag id.
+			index: u64,
+			/// The language code.
+			lang: BoundedVec<u8, T::LanguageCodeLimit>,
+			/// The display name in that language.
+			text: BoundedVec<u8, T::TranslationLimit>,
+			/// The deposit reserved for the translation.
+			deposit: BalanceOf<T>,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// The owner of a tag removed its display name in a language.
+		TagTranslationCleared {
+			/// The tag id.
+			index: u64,
+			/// The language code.
+			lang: BoundedVec<u8, T::LanguageCodeLimit>,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// The owner of a tag listed it for sale.
+		TagListed {
+			/// The tag id.
+			index: u64,
+			/// The account selling the tag.
+			seller: T::AccountId,
+			/// The price asked for the tag.
+			price: BalanceOf<T>,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// The owner of a tag withdrew it from sale.
+		TagDelisted {
+			/// The tag id.
+			index: u64,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// A listed tag was bought. The buyer is the new owner and holds the deposit.
+		TagSold {
+			/// The tag id.
+			index: u64,
+			/// The previous owner of the tag.
+			seller: T::AccountId,
+			/// The new owner of the tag.
+			buyer: T::AccountId,
+			/// The price paid to the seller.
+			price: BalanceOf<T>,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// An account reported a tag to the moderators.
+		TagReported {
+			/// The tag id.
+			index: u64,
+			/// The account who reported the tag.
+			reporter: T::AccountId,
+			/// Why the tag was reported.
+			reason: BoundedVec<u8, T::ReportReasonLimit>,
+			/// The bond reserved from the reporter.
+			bond: BalanceOf<T>,
+		},
+		/// A moderator upheld a report. The tag was removed and its deposit slashed.
+		ReportUpheld {
+			/// The tag id.
+			index: u64,
+			/// The account who reported the tag, and got its bond back.
+			reporter: T::AccountId,
+			/// The account that owned the removed tag.
+			owner: T::AccountId,
+			/// The amount slashed from the owner.
+			slashed: BalanceOf<T>,
+		},
+		/// The owner of a tag applied it to an account.
+		AccountTagged {
+			/// The tag id.
+			index: u64,
+			/// The account that received the tag.
+			account: T::AccountId,
+			/// When the tag was applied.
+			tagged_at: BlockTimeOf<T>,
+		},
+		/// A tag was removed from an account.
+		AccountUntagged {
+			/// The tag id.
+			index: u64,
+			/// The account that lost the tag.
+			account: T::AccountId,
+		},
+		/// A moderator dismissed a report. The bond of the reporter was slashed.
+		ReportDismissed {
+			/// The tag id.
+			index: u64,
+			/// The account who reported the tag.
+			reporter: T::AccountId,
+			/// The amount slashed from the reporter.
+			slashed: BalanceOf<T>,
+		},
+		/// A tag was applied to an item.
+		ItemTagged {
+			/// The tag id.
+			index: u64,
+			/// The collection of the item.
+			collection: T::CollectionId,
+			/// The item.
+			item: T::ItemId,
+			/// The account that applied the tag.
+			who: T::AccountId,
+			/// The reserved deposit.
+			deposit: BalanceOf<T>,
+			/// When the tag was applied.
+			tagged_at: BlockTimeOf<T>,
+		},
+		/// A tag was removed from an item.
+		ItemUntagged {
+			/// The tag id.
+			index: u64,
+			/// The collection of the item.
+			collection: T::CollectionId,
+			/// The item.
+			item: T::ItemId,
+		},
+		/// A tag was proposed for an item, waiting for the approval of its owner.
+		TagProposed {
+			/// The tag id.
+			index: u64,
+			/// The collection of the item.
+			collection: T::CollectionId,
+			/// The item.
+			item: T::ItemId,
+			/// The account proposing the tag.
+			who: T::AccountId,
+			/// The reserved deposit.
+			deposit: BalanceOf<T>,
+		},
+		/// The owner of an item accepted a proposed tag, and the deposit was returned.
+		ProposalAccepted {
+			/// The tag id.
+			index: u64,
+			/// The collection of the item.
+			collection: T::CollectionId,
+			/// The item.
+			item: T::ItemId,
+		},
+		/// The owner of an item rejected a proposed tag, and the deposit was slashed.
+		ProposalRejected {
+			/// The tag id.
+			index: u64,
+			/// The collection of the item.
+			collection: T::CollectionId,
+			/// The item.
+			item: T::ItemId,
+			/// The slashed amount.
+			slashed: BalanceOf<T>,
+		},
+		/// A proposed tag was withdrawn, and the deposit was returned.
+		ProposalWithdrawn {
+			/// The tag id.
+			index: u64,
+			/// The collection of the item.
+			collection: T::CollectionId,
+			/// The item.
+			item: T::ItemId,
+		},
+		/// The owner of a tag set or removed its usage fee.
+		UsageFeeSet {
+			/// The tag id.
+			index: u64,
+			/// The new fee, if any.
+			fee: Option<BalanceOf<T>>,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// The owner of a tag set the collections it is restricted to, or removed the scope with
+		/// an empty list.
+		TagScopeSet {
+			/// The tag id.
+			index: u64,
+			/// The collections in the scope.
+			collections: BoundedVec<T::CollectionId, T::MaxScopeCollections>,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// An account staked funds on a tag.
+		Staked {
+			/// The tag id.
+			index: u64,
+			/// The staker.
+			who: T::AccountId,
+			/// The amount staked.
+			amount: BalanceOf<T>,
+			/// The total stake on the tag.
+			total: BalanceOf<T>,
+		},
+		/// An account unstaked funds from a tag, which unlock after the unbonding period.
+		Unstaked {
+			/// The tag id.
+			index: u64,
+			/// The staker.
+			who: T::AccountId,
+			/// The amount unstaked.
+			amount: BalanceOf<T>,
+			/// The block the funds can be withdrawn at.
+			unlock_at: BlockNumberFor<T>,
+		},
+		/// An account withdrew its unlocked funds.
+		StakeWithdrawn {
+			/// The staker.
+			who: T::AccountId,
+			/// The amount returned.
+			amount: BalanceOf<T>,
+		},
+		/// The stake on a tag that was removed, merged or is being destroyed went back to its
+		/// staker, without unbonding.
+		StakeReleased {
+			/// The tag id.
+			index: u64,
+			/// The staker.
+			who: T::AccountId,
+			/// The amount returned.
+			amount: BalanceOf<T>,
+		},
+		/// An account paid the usage fee of a tag to its owner, to apply it to an item.
+		FeePaid {
+			/// The tag id.
+			index: u64,
+			/// The account that applied the tag.
+			who: T::AccountId,
+			/// The owner of the tag.
+			owner: T::AccountId,
+			/// The paid fee.
+			fee: BalanceOf<T>,
+		},
+		/// A tag was applied to a collection, and so to all its items.
+		CollectionTagged {
+			/// The tag id.
+			index: u64,
+			/// The collection.
+			collection: T::CollectionId,
+			/// When the tag was applied.
+			tagged_at: BlockTimeOf<T>,
+		},
+		/// A tag was removed from a collection.
+		CollectionUntagged {
+			/// The tag id.
+			index: u64,
+			/// The collection.
+			collection: T::CollectionId,
+		},
+		/// The attributes of some tagged items were written.
+		AttributesBackfilled {
+			/// The number of item tags processed.
+			count: u32,
+			/// Whether every item tag has been processed.
+			finished: bool,
+		},
+		/// The owner started destroying a tag. It can't be applied anymore.
+		DestructionStarted {
+			/// The tag id.
+			index: u64,
+		},
+		/// Some references to a tag being destroyed were removed.
+		ReferencesDestroyed {
+			/// The tag id.
+			index: u64,
+			/// The number of references removed.
+			removed: u32,
+			/// The number of references left.
+			remaining: u32,
+		},
+		/// A tag started being merged into another one.
+		MergeStarted {
+			/// The merged tag.
+			from: u64,
+			/// The tag it's merged into.
+			into: u64,
+		},
+		/// Some references of a tag being merged were moved to the other tag.
+		ReferencesMoved {
+			/// The merged tag.
+			from: u64,
+			/// The tag it's merged into.
+			into: u64,
+			/// The number of references moved.
+			moved: u32,
+			/// The number of references left.
+			remaining: u32,
+		},
+		/// A tag was merged into another one and removed. Its id and name resolve to the other
+		/// tag.
+		TagsMerged {
+			/// The merged tag.
+			from: u64,
+			/// The tag it was merged into.
+			into: u64,
+			/// The name of the merged tag.
+			name: BoundedVec<u8, T::TagNameLimit>,
+		},
+		/// The collection tags are minted in was created.
+		TagCollectionCreated {
+			/// The id of the collection.
+			collection: T::CollectionId,
+		},
+		/// The stored owner of a tag caught up with the owner of its item, which was transferred
+		/// outside of the pallet.
+		TagTransferred {
+			/// The tag id.
+			index: u64,
+			/// The previous owner.
+			from: T::AccountId,
+			/// The new owner, who holds the item.
+			to: T::AccountId,
+			/// When the tag was changed.
+			updated_at: BlockTimeOf<T>,
+		},
+		/// The merge of a tag was given up because the tag it was merged into was removed or
+		/// started being destroyed. The merged tag is live again.
+		MergeAborted {
+			/// The merged tag.
+			from: u64,
+			/// The tag it was merged into.
+			into: u64,
+		},
+	}
+
+	/// Errors that can be returned by this pallet.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The value retrieved was `None` as no value was previously set.
+		NoneValue,
+		/// There are more tags than possible to store on chain (u64 id limit).
+		StorageOverflow,
+		/// The tag with the given ID never existed in the network.
+		InvalidTag,
+		/// The tag existed but was destroyed, see its tombstone.
+		TagDestroyedAlready,
+		/// An user tried to modify a tag that it didn't create.
+		NotAllowed,
+		/// The tag already has an open report.
+		AlreadyReported,
+		/// The tag has no open report.
+		NotReported,
+		/// The tag has an open report and can't be modified until a moderator rules on it.
+		UnderReview,
+		/// The tag is still applied to some target.
+		TagInUse,
+		/// The target already has this tag.
+		AlreadyTagged,
+		/// The target doesn't have this tag.
+		NotTagged,
+		/// The deadline of the pre-signed payload has passed.
+		DeadlineExpired,
+		/// The signature doesn't match the payload and signer.
+		WrongSignature,
+		/// The nonce of the pre-signed payload isn't the one expected for the signer.
+		WrongNonce,
+		/// The tag isn't listed for sale.
+		NotForSale,
+		/// The price of the tag is higher than the maximum the buyer accepts.
+		PriceTooHigh,
+		/// The account already owns the tag.
+		AlreadyOwner,
+		/// Another tag already has this name.
+		NameTaken,
+		/// There is already a commitment with this hash.
+		AlreadyCommitted,
+		/// There is no commitment with this hash, or it belongs to another account.
+		NoCommitment,
+		/// The commitment is too recent to be revealed.
+		CommitmentTooNew,
+		/// The commitment is too old to be revealed.
+		CommitmentExpired,
+		/// Only the committer can clear a commitment before it expires.
+		CommitmentNotExpired,
+		/// The tag already has the maximum number of translations.
+		TooManyTranslations,
+		/// The tag has no translation for this language.
+		NoTranslation,
+		/// The tag is being destroyed.
+		TagDestroying,
+		/// The tag is not being destroyed.
+		NotDestroying,
+		/// The item doesn't exist.
+		UnknownItem,
+		/// The collection doesn't exist.
+		UnknownCollection,
+		/// The item or collection already has the maximum number of tags.
+		TooManyTags,
+		/// Tags are not mirrored into item attributes.
+		MirroringDisabled,
+		/// The tag must be proposed, and accepted by the owner of the item.
+		ApprovalRequired,
+		/// The tag was already proposed for the item.
+		AlreadyProposed,
+		/// The tag wasn't proposed for the item.
+		NoProposal,
+		/// The usage fee is above `MaxUsageFee`.
+		FeeTooHigh,
+		/// A tag can't be merged into itself.
+		SameTag,
+		/// The tag is being merged into a different tag.
+		WrongMergeTarget,
+		/// The collection of tags was already created.
+		TagCollectionExists,
+		/// The tag isn't minted as an item.
+		NotAnNft,
+		/// The stored owner of the tag already holds its item.
+		OwnerUpToDate,
+		/// The collection isn't in the scope of the tag.
+		OutOfScope,
+		/// The amount to stake or unstake is zero.
+		ZeroStake,
+		/// The account staked less than that on the tag.
+		NotEnoughStake,
+		/// The account has too many unstaked amounts unbonding.
+		TooManyUnbondingChunks,
+		/// The account has no unlocked funds.
+		NothingToWithdraw,
+		/// The limit must be at least one.
+		ZeroLimit,
+		/// The item of the tag was burned, so the tag has no owner.
+		TagItemBurned,
+		/// The stake of the account on the tag would be below `MinStake`.
+		StakeTooLow,
+		/// The usage fee of the tag is higher than the maximum the tagger accepts.
+		FeeAboveLimit,
+		/// The tag still has an owner, who has to remove it.
+		TagItemNotBurned,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Creates a tag with a name.
+		/// Tags are stored in TagMap, they contain a name, the creator, and the deposit reserved for them.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_tag(name.len() as u32))]
+		pub fn create_tag(origin: OriginFor<T>, name: BoundedVec<u8, T::TagNameLimit>) -> DispatchResult {
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_tag(who.clone(), name, who)?;
+
+			// Return a successful `DispatchResult`
+			Ok(())
+		}
+
+		/// Destroys a tag from the chain
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::destroy_tag())]
+		pub fn destroy_tag(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			let (name, creator, deposit) = Self::try_get_tag(tag_index)?;
+
+			if who != creator {
+				Err(Error::<T>::NotAllowed)?;
+			}
+
+			// Tags being merged or destroyed in steps are removed by those calls.
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+
+			// Owners can't escape a pending report by destroying the tag.
+			ensure!(!Reports::<T>::contains_key(tag_index), Error::<T>::UnderReview);
+
+			ensure!(TagReferences::<T>::get(tag_index) == 0, Error::<T>::TagInUse);
+
+			// Other pallets might still depend on the tag.
+			T::OnTagLifecycle::can_destroy(tag_index, &who)?;
+
+			Self::do_destroy_tag(tag_index, name, who, deposit);
+
+			Ok(())
+		}
+
+		/// Reports a tag to the moderators, reserving a bond from the reporter.
+		/// The bond is returned if the report is upheld, and slashed if it is dismissed.
+		/// Tags being destroyed can't be reported.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::report_tag(reason.len() as u32))]
+		pub fn report_tag(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			reason: BoundedVec<u8, T::ReportReasonLimit>,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+
+			ensure!(TagMap::<T>::contains_key(tag_index), Self::missing_tag(tag_index));
+			// Tags on their way out can't be removed by a moderator anymore
+			ensure!(TagStatuses::<T>::get(tag_index) != TagStatus::Destroying, Error::<T>::TagDestroying);
+			ensure!(!Reports::<T>::contains_key(tag_index), Error::<T>::AlreadyReported);
+
+			let bond = T::ReportBond::get();
+			T::Currency::reserve(&reporter, bond)?;
+
+			Reports::<T>::insert(tag_index, (reporter.clone(), reason.clone(), bond));
+
+			Self::deposit_event(Event::TagReported { index: tag_index, reporter, reason, bond });
+
+			Ok(())
+		}
+
+		/// Upholds the report against a tag. The tag is removed, its deposit is slashed to the
+		/// configured sink, and the reporter gets its bond back.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::uphold_report())]
+		pub fn uphold_report(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			T::ModeratorOrigin::ensure_origin(origin)?;
+
+			let (reporter, _reason, bond) =
+				Reports::<T>::take(tag_index).ok_or(Error::<T>::NotReported)?;
+			let (name, owner, deposit) =
+				TagMap::<T>::take(tag_index).ok_or_else(|| Self::missing_tag(tag_index))?;
+			TagNames::<T>::remove(name);
+
+			T::Currency::unreserve(&reporter, bond);
+
+			let payer = Self::deposit_payer(tag_index, &owner);
+			Self::burn_tag_item(tag_index);
+			TagDepositors::<T>::remove(tag_index);
+			Listings::<T>::remove(tag_index);
+			UsageFees::<T>::remove(tag_index);
+			TagScopes::<T>::remove(tag_index);
+			TotalStakes::<T>::remove(tag_index);
+			TagActivities::<T>::remove(tag_index);
+			if let TagStatus::Merging { into } = TagStatuses::<T>::take(tag_index) {
+				// The merge won't finish, so it doesn't keep the other tag around anymore.
+				Self::remove_reference(into);
+			}
+			Self::clear_translations(tag_index);
+			Self::bury_tag(tag_index, None, None);
+
+			let (imbalance, _) = T::Currency::slash_reserved(&payer, deposit);
+			let slashed = imbalance.peek();
+			T::Slash::on_unbalanced(imbalance);
+
+			// References to the tag are left behind, ids are never reused so they are just ignored.
+			// Stakes are released by a task, as for any removed tag.
+			// Moderation can't be vetoed, but other pallets still need to know the tag is gone.
+			T::OnTagLifecycle::on_destroyed(tag_index, &owner);
+
+			Self::deposit_event(Event::ReportUpheld { index: tag_index, reporter, owner, slashed });
+
+			Ok(())
+		}
+
+		/// Dismisses the report against a tag. The tag is kept and the bond of the reporter is
+		/// slashed to the configured sink.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::dismiss_report())]
+		pub fn dismiss_report(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			T::ModeratorOrigin::ensure_origin(origin)?;
+
+			let (reporter, _reason, bond) =
+				Reports::<T>::take(tag_index).ok_or(Error::<T>::NotReported)?;
+
+			let (imbalance, _) = T::Currency::slash_reserved(&reporter, bond);
+			let slashed = imbalance.peek();
+			T::Slash::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::ReportDismissed { index: tag_index, reporter, slashed });
+
+			Ok(())
+		}
+
+		/// Applies a tag to an account. Only the owner of the tag can grant it.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::tag_account())]
+		pub fn tag_account(origin: OriginFor<T>, tag_index: u64, account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, creator, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == creator, Error::<T>::NotAllowed);
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+			ensure!(!AccountTags::<T>::contains_key(&account, tag_index), Error::<T>::AlreadyTagged);
+
+			Self::add_reference(tag_index)?;
+			AccountTags::<T>::insert(&account, tag_index, ());
+			AccountsByTag::<T>::insert(tag_index, &account, ());
+
+			let tagged_at = Self::record_tagging(tag_index);
+
+			Self::deposit_event(Event::AccountTagged { index: tag_index, account, tagged_at });
+
+			Ok(())
+		}
+
+		/// Removes a tag from an account. It can be done by the owner of the tag, or by the tagged
+		/// account itself, even if the tag doesn't exist anymore.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::untag_account())]
+		pub fn untag_account(origin: OriginFor<T>, tag_index: u64, account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(AccountTags::<T>::contains_key(&account, tag_index), Error::<T>::NotTagged);
+
+			if who != account {
+				let (_name, creator, _deposit) = Self::try_get_tag(tag_index)?;
+				ensure!(who == creator, Error::<T>::NotAllowed);
+			}
+
+			AccountTags::<T>::remove(&account, tag_index);
+			AccountsByTag::<T>::remove(tag_index, &account);
+			Self::remove_reference(tag_index);
+
+			Self::deposit_event(Event::AccountUntagged { index: tag_index, account });
+
+			Ok(())
+		}
+
+		/// Creates a tag from a payload signed by its future owner, submitted by a relayer that
+		/// pays for the transaction. The payload decides who pays the deposit.
+		///
+		/// The signature covers the payload along with the pallet and the genesis hash of the
+		/// chain, see [`Pallet::signed_message`], so it can't be replayed on other chains.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::create_tag_with_signature(payload.name.len() as u32))]
+		pub fn create_tag_with_signature(
+			origin: OriginFor<T>,
+			payload: PreSignedTagOf<T>,
+			signer: T::AccountId,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+
+			let msg = Self::signed_message(&payload);
+			ensure!(signature.verify(&*msg, &signer), Error::<T>::WrongSignature);
+
+			let PreSignedTag { name, deadline, nonce, signer_pays_deposit } = payload;
+
+			ensure!(frame_system::Pallet::<T>::block_number() <= deadline, Error::<T>::DeadlineExpired);
+
+			// Each nonce can only be used once, which prevents replaying the payload.
+			SignerNonces::<T>::try_mutate(&signer, |expected| -> DispatchResult {
+				ensure!(nonce == *expected, Error::<T>::WrongNonce);
+				*expected = expected.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+				Ok(())
+			})?;
+
+			let deposit_payer = if signer_pays_deposit { signer.clone() } else { relayer.clone() };
+			let index = Self::do_create_tag(signer.clone(), name, deposit_payer.clone())?;
+
+			Self::deposit_event(Event::TagRelayed { index, signer, relayer, deposit_payer });
+
+			Ok(())
+		}
+
+		/// Lists a tag for sale at the given price, or updates the price of a listed tag.
+		/// Only the owner of the tag can list it.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::list_tag())]
+		pub fn list_tag(origin: OriginFor<T>, tag_index: u64, price: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+
+			// Reported tags are frozen until a moderator rules on them.
+			ensure!(!Reports::<T>::contains_key(tag_index), Error::<T>::UnderReview);
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+
+			Listings::<T>::insert(tag_index, price);
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::TagListed { index: tag_index, seller: who, price, updated_at });
+
+			Ok(())
+		}
+
+		/// Buys a listed tag. The price is paid to the owner, and the deposit of the tag is
+		/// reserved from the buyer while the previous payer gets it back.
+		/// Fails if the price is higher than `max_price`, which protects from price changes.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::buy_tag())]
+		pub fn buy_tag(origin: OriginFor<T>, tag_index: u64, max_price: BalanceOf<T>) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			let price = Listings::<T>::get(tag_index).ok_or(Error::<T>::NotForSale)?;
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+			let (name, stored_owner, deposit) =
+				TagMap::<T>::get(tag_index).ok_or_else(|| Self::missing_tag(tag_index))?;
+			let seller = Self::current_owner(tag_index, stored_owner.clone())?;
+			// The listing was made by the previous owner of the item.
+			ensure!(seller == stored_owner, Error::<T>::NotForSale);
+			ensure!(buyer != seller, Error::<T>::AlreadyOwner);
+			ensure!(!Reports::<T>::contains_key(tag_index), Error::<T>::UnderReview);
+
+			T::Currency::transfer(&buyer, &seller, price, ExistenceRequirement::KeepAlive)?;
+
+			// The buyer takes over the deposit.
+			T::Currency::reserve(&buyer, deposit)?;
+			T::Currency::unreserve(&Self::deposit_payer(tag_index, &seller), deposit);
+			Self::set_deposit_payer(tag_index, &buyer, &buyer);
+
+			if let (Some(collection), Some(item)) =
+				(TagCollection::<T>::get(), Self::tag_item_id(tag_index))
+			{
+				if T::Nfts::owner(&collection, &item).is_some() {
+					T::Nfts::transfer(&collection, &item, &buyer)?;
+				}
+			}
+			TagMap::<T>::insert(tag_index, (name, buyer.clone(), deposit));
+			Listings::<T>::remove(tag_index);
+
+			T::OnTagLifecycle::on_transferred(tag_index, &seller, &buyer);
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::TagSold { index: tag_index, seller, buyer, price, updated_at });
+
+			Ok(())
+		}
+
+		/// Withdraws a tag from sale. Only the owner of the tag can delist it.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::delist_tag())]
+		pub fn delist_tag(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+			ensure!(Listings::<T>::take(tag_index).is_some(), Error::<T>::NotForSale);
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::TagDelisted { index: tag_index, updated_at });
+
+			Ok(())
+		}
+
+		/// Commits to a tag name without revealing it, reserving a deposit.
+		/// The hash is `Hashing(SCALE((committer, name, salt)))`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::commit_tag())]
+		pub fn commit_tag(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Commitments::<T>::contains_key(hash), Error::<T>::AlreadyCommitted);
+
+			let deposit = T::CommitmentDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Commitments::<T>::insert(hash, (who.clone(), now, deposit));
+
+			Self::deposit_event(Event::TagCommitted { hash, who });
+
+			Ok(())
+		}
+
+		/// Reveals the name of a commitment and creates the tag, returning the commitment deposit.
+		/// The commitment must be at least `MinCommitmentAge` blocks old, and at most
+		/// `MaxCommitmentAge`. If the name was taken in between, the first reveal wins.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::reveal_tag(name.len() as u32))]
+		pub fn reveal_tag(
+			origin: OriginFor<T>,
+			name: BoundedVec<u8, T::TagNameLimit>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let hash = T::Hashing::hash_of(&(&who, &name, &salt));
+			let (_committer, committed_at, deposit) =
+				Commitments::<T>::take(hash).ok_or(Error::<T>::NoCommitment)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= committed_at.saturating_add(T::MinCommitmentAge::get()),
+				Error::<T>::CommitmentTooNew
+			);
+			ensure!(
+				now <= committed_at.saturating_add(T::MaxCommitmentAge::get()),
+				Error::<T>::CommitmentExpired
+			);
+
+			T::Currency::unreserve(&who, deposit);
+
+			let index = Self::do_create_tag(who.clone(), name, who)?;
+
+			Self::deposit_event(Event::TagRevealed { index, hash });
+
+			Ok(())
+		}
+
+		/// Removes a commitment without revealing it, returning the deposit to the committer.
+		/// The committer can do it at any time, anyone else only once it has expired.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::clear_commitment())]
+		pub fn clear_commitment(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (committer, committed_at, deposit) =
+				Commitments::<T>::take(hash).ok_or(Error::<T>::NoCommitment)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				who == committer || now > committed_at.saturating_add(T::MaxCommitmentAge::get()),
+				Error::<T>::CommitmentNotExpired
+			);
+
+			T::Currency::unreserve(&committer, deposit);
+
+			Self::deposit_event(Event::CommitmentCleared { hash, who: committer });
+
+			Ok(())
+		}
+
+		/// Sets the display name of a tag in a language, replacing the previous one.
+		/// A deposit is reserved per byte of the language code and the name, and the deposit of
+		/// the replaced translation is returned.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_tag_translation(lang.len() as u32, text.len() as u32))]
+		pub fn set_tag_translation(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			lang: BoundedVec<u8, T::LanguageCodeLimit>,
+			text: BoundedVec<u8, T::TranslationLimit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+
+			match TagTranslations::<T>::get(tag_index, &lang) {
+				Some((_text, old_payer, old_deposit)) => {
+					T::Currency::unreserve(&old_payer, old_deposit);
+				},
+				None => {
+					TranslationCounts::<T>::try_mutate(tag_index, |count| -> DispatchResult {
+						ensure!(*count < T::MaxTranslations::get(), Error::<T>::TooManyTranslations);
+						*count += 1;
+						Ok(())
+					})?;
+				},
+			}
+
+			let bytes = lang.len().saturating_add(text.len()) as u32;
+			let deposit = T::TranslationDepositPerByte::get().saturating_mul(bytes.into());
+			T::Currency::reserve(&who, deposit)?;
+
+			TagTranslations::<T>::insert(tag_index, &lang, (text.clone(), who, deposit));
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::TagTranslationSet { index: tag_index, lang, text, deposit, updated_at });
+
+			Ok(())
+		}
+
+		/// Removes the display name of a tag in a language, returning its deposit.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::clear_tag_translation())]
+		pub fn clear_tag_translation(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			lang: BoundedVec<u8, T::LanguageCodeLimit>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+
+			let (_text, payer, deposit) =
+				TagTranslations::<T>::take(tag_index, &lang).ok_or(Error::<T>::NoTranslation)?;
+			T::Currency::unreserve(&payer, deposit);
+			TranslationCounts::<T>::mutate_exists(tag_index, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::TagTranslationCleared { index: tag_index, lang, updated_at });
+
+			Ok(())
+		}
+
+		/// Starts destroying a tag that might be applied to too many targets to be destroyed in a
+		/// single block. The tag can't be applied anymore, and its listing is removed.
+		/// The references are then removed with `destroy_tag_references`, and the tag with
+		/// `finish_destroy_tag`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::start_destroy_tag())]
+		pub fn start_destroy_tag(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+
+			// Owners can't escape a pending report by destroying the tag.
+			ensure!(!Reports::<T>::contains_key(tag_index), Error::<T>::UnderReview);
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+
+			// Other pallets might still depend on the tag.
+			T::OnTagLifecycle::can_destroy(tag_index, &who)?;
+
+			TagStatuses::<T>::insert(tag_index, TagStatus::Destroying);
+			Listings::<T>::remove(tag_index);
+
+			Self::deposit_event(Event::DestructionStarted { index: tag_index });
+
+			Ok(())
+		}
+
+		/// Removes up to `max` references to a tag being destroyed, bounded by
+		/// `RemoveReferencesLimit`. Anyone can call it.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::destroy_tag_references(
+			(*max).min(T::RemoveReferencesLimit::get())
+		))]
+		pub fn destroy_tag_references(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Destroying, Error::<T>::NotDestroying);
+
+			let max = max.min(T::RemoveReferencesLimit::get());
+			let mut removed = 0u32;
+			for (account, ()) in AccountsByTag::<T>::drain_prefix(tag_index).take(max as usize) {
+				AccountTags::<T>::remove(&account, tag_index);
+				removed += 1;
+			}
+			for (collection, ()) in CollectionsByTag::<T>::drain_prefix(tag_index).take((max - removed) as usize) {
+				Self::forget_collection_tag(tag_index, collection);
+				removed += 1;
+			}
+			for (item, (tagger, deposit)) in ItemsByTag::<T>::drain_prefix(tag_index).take((max - removed) as usize) {
+				T::Currency::unreserve(&tagger, deposit);
+				Self::forget_item_tag(tag_index, item);
+				removed += 1;
+			}
+
+			TagReferences::<T>::mutate_exists(tag_index, |count| {
+				*count = count.map(|count| count.saturating_sub(removed)).filter(|count| *count > 0);
+			});
+			let remaining = TagReferences::<T>::get(tag_index);
+
+			Self::deposit_event(Event::ReferencesDestroyed { index: tag_index, removed, remaining });
+
+			Ok(Some(T::WeightInfo::destroy_tag_references(removed)).into())
+		}
+
+		/// Destroys a tag once all its references were removed, returning its deposit.
+		/// Anyone can call it.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::finish_destroy_tag())]
+		pub fn finish_destroy_tag(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Destroying, Error::<T>::NotDestroying);
+			ensure!(TagReferences::<T>::get(tag_index) == 0, Error::<T>::TagInUse);
+
+			let (name, owner, deposit) = Self::get_removed_tag(tag_index)?;
+
+			Self::do_destroy_tag(tag_index, name, owner, deposit);
+
+			Ok(())
+		}
+
+		/// Applies a tag to an item. Who can apply it is decided by `TagPermission`, and an item
+		/// can't carry more than `MaxTagsPerItem` tags. The usage fee of the tag, if any, can't be
+		/// higher than `max_fee`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::tag_item())]
+		pub fn tag_item(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			let item_owner = T::Nfts::owner(&collection, &item).ok_or(Error::<T>::UnknownItem)?;
+			match T::TagPermission::policy(tag_index, &collection) {
+				TaggingPolicy::Open => {},
+				TaggingPolicy::TagOwner => ensure!(who == owner, Error::<T>::NotAllowed),
+				TaggingPolicy::ItemOwnerApproval => ensure!(who == item_owner, Error::<T>::ApprovalRequired),
+			}
+
+			Self::do_tag_item(tag_index, (collection, item), &who, max_fee)
+		}
+
+		/// Removes a tag from an item. It can be done by the account that applied it, by the owner
+		/// of the tag, or by the owner of the item, even if the tag doesn't exist anymore.
+		///
+		/// The tagging deposit goes back to the account that applied the tag, unless
+		/// `SlashRemovedTaggings` is set and the owner of the item removes a tag applied by
+		/// someone else.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::untag_item())]
+		pub fn untag_item(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (tagger, deposit) =
+				ItemsByTag::<T>::get(tag_index, (collection, item)).ok_or(Error::<T>::NotTagged)?;
+
+			let by_item_owner = T::Nfts::owner(&collection, &item) == Some(who.clone());
+			if who != tagger && !by_item_owner {
+				let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+				ensure!(who == owner, Error::<T>::NotAllowed);
+			}
+
+			if who != tagger && by_item_owner && T::SlashRemovedTaggings::get() {
+				let (imbalance, _) = T::Currency::slash_reserved(&tagger, deposit);
+				T::Slash::on_unbalanced(imbalance);
+			} else {
+				T::Currency::unreserve(&tagger, deposit);
+			}
+
+			ItemsByTag::<T>::remove(tag_index, (collection, item));
+			Self::forget_item_tag(tag_index, (collection, item));
+			Self::remove_reference(tag_index);
+
+			Self::deposit_event(Event::ItemUntagged { index: tag_index, collection, item });
+
+			Ok(())
+		}
+
+		/// Applies a tag to a collection, so every item of the collection inherits it. Only the
+		/// owner of the tag can apply it, and a collection can't carry more than `MaxTagsPerItem`
+		/// tags.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::tag_collection())]
+		pub fn tag_collection(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+			ensure!(T::Nfts::collection_owner(&collection).is_some(), Error::<T>::UnknownCollection);
+			Self::ensure_in_scope(tag_index, &collection)?;
+			ensure!(!CollectionsByTag::<T>::contains_key(tag_index, collection), Error::<T>::AlreadyTagged);
+
+			CollectionTagCounts::<T>::try_mutate(collection, |count| -> DispatchResult {
+				ensure!(*count < T::MaxTagsPerItem::get(), Error::<T>::TooManyTags);
+				*count += 1;
+				Ok(())
+			})?;
+			Self::add_reference(tag_index)?;
+			CollectionsByTag::<T>::insert(tag_index, collection, ());
+			TagsByCollection::<T>::insert(collection, tag_index, ());
+
+			let tagged_at = Self::record_tagging(tag_index);
+
+			Self::deposit_event(Event::CollectionTagged { index: tag_index, collection, tagged_at });
+
+			Ok(())
+		}
+
+		/// Removes a tag from a collection. It can be done by the owner of the tag, or by the
+		/// owner of the collection, even if the tag doesn't exist anymore.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::untag_collection())]
+		pub fn untag_collection(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(CollectionsByTag::<T>::contains_key(tag_index, collection), Error::<T>::NotTagged);
+
+			if T::Nfts::collection_owner(&collection) != Some(who.clone()) {
+				let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+				ensure!(who == owner, Error::<T>::NotAllowed);
+			}
+
+			CollectionsByTag::<T>::remove(tag_index, collection);
+			Self::forget_collection_tag(tag_index, collection);
+			Self::remove_reference(tag_index);
+
+			Self::deposit_event(Event::CollectionUntagged { index: tag_index, collection });
+
+			Ok(())
+		}
+
+		/// Proposes a tag for an item whose owner must approve it, reserving `ProposalDeposit`.
+		/// The usage fee charged when the proposal is accepted can't be higher than `max_fee`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::propose_item_tag())]
+		pub fn propose_item_tag(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(TagMap::<T>::contains_key(tag_index), Self::missing_tag(tag_index));
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+			ensure!(T::Nfts::owner(&collection, &item).is_some(), Error::<T>::UnknownItem);
+			ensure!(
+				T::TagPermission::policy(tag_index, &collection) == TaggingPolicy::ItemOwnerApproval,
+				Error::<T>::NotAllowed
+			);
+			Self::ensure_in_scope(tag_index, &collection)?;
+			ensure!(!ItemsByTag::<T>::contains_key(tag_index, (collection, item)), Error::<T>::AlreadyTagged);
+			ensure!(!TagProposals::<T>::contains_key((collection, item), tag_index), Error::<T>::AlreadyProposed);
+
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			TagProposals::<T>::insert((collection, item), tag_index, (who.clone(), deposit, max_fee));
+
+			Self::deposit_event(Event::TagProposed { index: tag_index, collection, item, who, deposit });
+
+			Ok(())
+		}
+
+		/// Accepts a proposed tag, applying it to the item. Only the owner of the item can accept
+		/// it, and the proposer gets its deposit back, paying the tagging deposit instead.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::accept_item_tag())]
+		pub fn accept_item_tag(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (proposer, deposit, max_fee) =
+				TagProposals::<T>::take((collection, item), tag_index).ok_or(Error::<T>::NoProposal)?;
+			ensure!(T::Nfts::owner(&collection, &item) == Some(who), Error::<T>::NotAllowed);
+			ensure!(TagMap::<T>::contains_key(tag_index), Self::missing_tag(tag_index));
+
+			T::Currency::unreserve(&proposer, deposit);
+			Self::do_tag_item(tag_index, (collection, item), &proposer, max_fee)?;
+
+			Self::deposit_event(Event::ProposalAccepted { index: tag_index, collection, item });
+
+			Ok(())
+		}
+
+		/// Rejects a proposed tag. Only the owner of the item can reject it, and the deposit of
+		/// the proposer is slashed to the configured sink.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::reject_item_tag())]
+		pub fn reject_item_tag(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (proposer, deposit, _max_fee) =
+				TagProposals::<T>::take((collection, item), tag_index).ok_or(Error::<T>::NoProposal)?;
+			ensure!(T::Nfts::owner(&collection, &item) == Some(who), Error::<T>::NotAllowed);
+
+			let (imbalance, _) = T::Currency::slash_reserved(&proposer, deposit);
+			let slashed = imbalance.peek();
+			T::Slash::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::ProposalRejected { index: tag_index, collection, item, slashed });
+
+			Ok(())
+		}
+
+		/// Withdraws a proposed tag before the owner of the item answers it, returning the
+		/// deposit. Only the proposer can withdraw it.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::withdraw_item_tag())]
+		pub fn withdraw_item_tag(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (proposer, deposit, _max_fee) =
+				TagProposals::<T>::get((collection, item), tag_index).ok_or(Error::<T>::NoProposal)?;
+			ensure!(who == proposer, Error::<T>::NotAllowed);
+
+			TagProposals::<T>::remove((collection, item), tag_index);
+			T::Currency::unreserve(&proposer, deposit);
+
+			Self::deposit_event(Event::ProposalWithdrawn { index: tag_index, collection, item });
+
+			Ok(())
+		}
+
+		/// Sets the fee other accounts pay to the owner of a tag when they apply it to an item, or
+		/// removes it with `None`. Only the owner of the tag can set it, up to `MaxUsageFee`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::set_usage_fee())]
+		pub fn set_usage_fee(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			fee: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+
+			match fee {
+				Some(fee) => {
+					ensure!(fee <= T::MaxUsageFee::get(), Error::<T>::FeeTooHigh);
+					UsageFees::<T>::insert(tag_index, fee);
+				},
+				None => UsageFees::<T>::remove(tag_index),
+			}
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::UsageFeeSet { index: tag_index, fee, updated_at });
+
+			Ok(())
+		}
+
+		/// Restricts a tag to items of the given collections, and to the collections themselves,
+		/// or removes the restriction with an empty list. Only the owner of the tag can set it.
+		///
+		/// Tags already applied outside of the new scope are kept, as are the references moved
+		/// by a merge.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_tag_scope())]
+		pub fn set_tag_scope(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			collections: BoundedVec<T::CollectionId, T::MaxScopeCollections>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(who == owner, Error::<T>::NotAllowed);
+
+			if collections.is_empty() {
+				TagScopes::<T>::remove(tag_index);
+			} else {
+				TagScopes::<T>::insert(tag_index, &collections);
+			}
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_event(Event::TagScopeSet { index: tag_index, collections, updated_at });
+
+			Ok(())
+		}
+
+		/// Stakes `amount` on a live tag, reserving it, to signal that the tag matters. Stakes
+		/// add up to at least `MinStake`, and clients rank tags by their total stake through the
+		/// runtime API.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::stake_on_tag())]
+		pub fn stake_on_tag(
+			origin: OriginFor<T>,
+			tag_index: u64,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroStake);
+			Self::try_get_tag(tag_index)?;
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+
+			let stake = Stakes::<T>::get(tag_index, &who).unwrap_or_default().saturating_add(amount);
+			ensure!(stake >= T::MinStake::get(), Error::<T>::StakeTooLow);
+
+			T::Currency::reserve(&who, amount)?;
+			Stakes::<T>::insert(tag_index, &who, stake);
+			let total = TotalStakes::<T>::mutate(tag_index, |total| {
+				*total = total.saturating_add(amount);
+				*total
+			});
+
+			Self::deposit_event(Event::Staked { index: tag_index, who, amount, total });
+
+			Ok(())
+		}
+
+		/// Unstakes `amount` from a tag. It stops counting for the tag at once, but stays
+		/// reserved for `StakeUnbondingPeriod` blocks, after which `withdraw_unbonded` returns it.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::unstake())]
+		pub fn unstake(origin: OriginFor<T>, tag_index: u64, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroStake);
+			let stake = Stakes::<T>::get(tag_index, &who).ok_or(Error::<T>::NotEnoughStake)?;
+			ensure!(amount <= stake, Error::<T>::NotEnoughStake);
+			ensure!(amount == stake || stake - amount >= T::MinStake::get(), Error::<T>::StakeTooLow);
+
+			let unlock_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::StakeUnbondingPeriod::get());
+			Unbonding::<T>::try_append(&who, (amount, unlock_at))
+				.map_err(|_| Error::<T>::TooManyUnbondingChunks)?;
+
+			if amount == stake {
+				Stakes::<T>::remove(tag_index, &who);
+			} else {
+				Stakes::<T>::insert(tag_index, &who, stake - amount);
+			}
+			Self::reduce_total_stake(tag_index, amount);
+
+			Self::deposit_event(Event::Unstaked { index: tag_index, who, amount, unlock_at });
+
+			Ok(())
+		}
+
+		/// Returns the unstaked funds of the caller whose unbonding period is over.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut amount = BalanceOf::<T>::zero();
+			Unbonding::<T>::try_mutate_exists(&who, |chunks| -> DispatchResult {
+				let unlocking = chunks.as_mut().ok_or(Error::<T>::NothingToWithdraw)?;
+				unlocking.retain(|(value, unlock_at)| {
+					let unlocked = *unlock_at <= now;
+					if unlocked {
+						amount = amount.saturating_add(*value);
+					}
+					!unlocked
+				});
+				ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+				if unlocking.is_empty() {
+					*chunks = None;
+				}
+				Ok(())
+			})?;
+
+			T::Currency::unreserve(&who, amount);
+
+			Self::deposit_event(Event::StakeWithdrawn { who, amount });
+
+			Ok(())
+		}
+
+		/// Merges the tag `from` into `into`, moving up to `max` of its references to `into` per
+		/// call, bounded by `RemoveReferencesLimit`.
+		///
+		/// The merge is started by `ForceOrigin`, or by the owner of both tags, and anyone can
+		/// resume it. Once `from` has no references left it's removed and its deposit returned,
+		/// and both its id and its name resolve to `into`. If `into` is removed or starts being
+		/// destroyed in the meantime, resuming the merge gives it up and `from` is live again.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::merge_tags((*max).min(T::RemoveReferencesLimit::get())))]
+		pub fn merge_tags(
+			origin: OriginFor<T>,
+			from: u64,
+			into: u64,
+			max: u32,
+		) -> DispatchResultWithPostInfo {
+			let maybe_who = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let (name, owner, deposit) = Self::try_get_tag(from)?;
+			match TagStatuses::<T>::get(from) {
+				TagStatus::Live => {
+					ensure!(from != into, Error::<T>::SameTag);
+					let (_name, into_owner, _deposit) = Self::try_get_tag(into)?;
+					if let Some(who) = maybe_who {
+						ensure!(who == owner && who == into_owner, Error::<T>::NotAllowed);
+					}
+					ensure!(TagStatuses::<T>::get(into) == TagStatus::Live, Error::<T>::TagDestroying);
+					ensure!(!Reports::<T>::contains_key(from), Error::<T>::UnderReview);
+					T::OnTagLifecycle::can_destroy(from, &owner)?;
+
+					// `into` can't be destroyed before the merge finishes.
+					Self::add_reference(into)?;
+					TagStatuses::<T>::insert(from, TagStatus::Merging { into });
+					Listings::<T>::remove(from);
+
+					Self::deposit_event(Event::MergeStarted { from, into });
+				},
+				TagStatus::Merging { into: target } => {
+					ensure!(target == into, Error::<T>::WrongMergeTarget);
+					if !TagMap::<T>::contains_key(into) || TagStatuses::<T>::get(into) != TagStatus::Live {
+						// References moved so far stay with `into` and go away with it.
+						TagStatuses::<T>::remove(from);
+						Self::remove_reference(into);
+
+						Self::deposit_event(Event::MergeAborted { from, into });
+
+						return Ok(Some(T::WeightInfo::merge_tags(0)).into())
+					}
+				},
+				TagStatus::Destroying => return Err(Error::<T>::TagDestroying.into()),
+			}
+
+			let moved = Self::move_references(from, into, max.min(T::RemoveReferencesLimit::get()));
+			let remaining = TagReferences::<T>::get(from);
+
+			Self::deposit_event(Event::ReferencesMoved { from, into, moved, remaining });
+
+			if remaining == 0 {
+				if let Some((reporter, _reason, bond)) = Reports::<T>::take(from) {
+					// The tag is gone, so there is nothing left to rule on.
+					T::Currency::unreserve(&reporter, bond);
+				}
+				Self::release_tag(from, &owner, deposit);
+				TagNames::<T>::insert(&name, into);
+				TagAliases::<T>::insert(from, into);
+				Self::remove_reference(into);
+				Self::bury_tag(from, Some(owner.clone()), Some(into));
+
+				T::OnTagLifecycle::on_destroyed(from, &owner);
+
+				Self::deposit_tag_event(from, &owner, Event::TagsMerged { from, into, name });
+			}
+
+			Ok(Some(T::WeightInfo::merge_tags(moved)).into())
+		}
+
+		/// Creates the collection tags are minted in, owned by the account of the pallet. From
+		/// then on, every new tag is also an item of the collection, and whoever holds the item
+		/// owns the tag.
+		///
+		/// The account of the pallet pays the deposit of the collection in `pallet_nfts`, so it
+		/// must be funded first, and the deposit of each item is paid by the account paying the
+		/// deposit of its tag.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::create_tag_collection())]
+		pub fn create_tag_collection(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!TagCollection::<T>::exists(), Error::<T>::TagCollectionExists);
+
+			let account = Self::account_id();
+			let collection =
+				T::Nfts::create_collection(&account, &account, &T::CollectionConfig::default())?;
+			TagCollection::<T>::put(collection);
+			FirstTagItem::<T>::put(TagIndex::<T>::get());
+
+			Self::deposit_event(Event::TagCollectionCreated { collection });
+
+			Ok(())
+		}
+
+		/// Updates the stored owner of a tag to the holder of its item, after the item was
+		/// transferred outside of the pallet. Any account can call it.
+		///
+		/// Calls of the pallet already treat the holder of the item as the owner, this brings the
+		/// stored owner, events and other pallets up to date. The deposit of the tag stays with
+		/// the account that paid it.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::sync_tag_owner())]
+		pub fn sync_tag_owner(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let (name, from, deposit) =
+				TagMap::<T>::get(tag_index).ok_or_else(|| Self::missing_tag(tag_index))?;
+			let to = Self::item_owner(tag_index).ok_or(Error::<T>::NotAnNft)?;
+			ensure!(to != from, Error::<T>::OwnerUpToDate);
+
+			TagMap::<T>::insert(tag_index, (name, to.clone(), deposit));
+			// The listing was made by the previous owner.
+			Listings::<T>::remove(tag_index);
+
+			T::OnTagLifecycle::on_transferred(tag_index, &from, &to);
+
+			let updated_at = Self::touch_tag(tag_index);
+
+			Self::deposit_tag_event(tag_index, &to, Event::TagTransferred {
+				index: tag_index, from, to: to.clone(), updated_at
+			});
+
+			Ok(())
+		}
+
+		/// Removes a tag whose item was burned, so it has no owner to remove it. Any account can
+		/// call it, and the deposit goes back to the account that paid it.
+		///
+		/// A tag without references is destroyed at once. Otherwise its destruction starts, and
+		/// it finishes as for any other tag. A merge of the tag is given up.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::remove_burned_tag())]
+		pub fn remove_burned_tag(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let (name, owner, deposit) =
+				TagMap::<T>::get(tag_index).ok_or_else(|| Self::missing_tag(tag_index))?;
+			ensure!(
+				matches!(Self::current_owner(tag_index, owner.clone()), Err(Error::<T>::TagItemBurned)),
+				Error::<T>::TagItemNotBurned
+			);
+			// Moderators rule on reported tags, which might slash the deposit.
+			ensure!(!Reports::<T>::contains_key(tag_index), Error::<T>::UnderReview);
+
+			match TagStatuses::<T>::get(tag_index) {
+				TagStatus::Live => (),
+				TagStatus::Merging { into } => {
+					TagStatuses::<T>::remove(tag_index);
+					Self::remove_reference(into);
+
+					Self::deposit_event(Event::MergeAborted { from: tag_index, into });
+				},
+				TagStatus::Destroying => return Err(Error::<T>::TagDestroying.into()),
+			}
+
+			// Other pallets might still depend on the tag.
+			T::OnTagLifecycle::can_destroy(tag_index, &owner)?;
+
+			if TagReferences::<T>::get(tag_index) == 0 {
+				Self::do_destroy_tag(tag_index, name, owner, deposit);
+			} else {
+				TagStatuses::<T>::insert(tag_index, TagStatus::Destroying);
+				Listings::<T>::remove(tag_index);
+
+				Self::deposit_event(Event::DestructionStarted { index: tag_index });
+			}
+
+			Ok(())
+		}
+
+		/// Writes the attributes of up to `limit` item tags that were applied before mirroring
+		/// was enabled. It resumes where the previous call stopped, until every item tag is
+		/// processed.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::backfill_attributes(*limit))]
+		pub fn backfill_attributes(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(T::MirrorToAttributes::get(), Error::<T>::MirroringDisabled);
+			// A call that processes nothing can't tell whether it finished.
+			ensure!(limit > 0, Error::<T>::ZeroLimit);
+
+			let item_tags = match AttributeBackfill::<T>::get() {
+				Some((tag_index, item)) =>
+					ItemsByTag::<T>::iter_keys_from(ItemsByTag::<T>::hashed_key_for(tag_index, item)),
+				None => ItemsByTag::<T>::iter_keys(),
+			};
+
+			let mut count = 0u32;
+			let mut last = None;
+			for (tag_index, item) in item_tags.take(limit as usize) {
+				// Stale tags are left for the cleanup tasks.
+				if T::Nfts::owner(&item.0, &item.1).is_some() && TagMap::<T>::contains_key(tag_index) {
+					Self::mirror_item_tag(tag_index, &item)?;
+				}
+				count += 1;
+				last = Some((tag_index, item));
+			}
+
+			let finished = count < limit;
+			if finished {
+				AttributeBackfill::<T>::kill();
+			} else {
+				AttributeBackfill::<T>::set(last);
+			}
+
+			Self::deposit_event(Event::AttributesBackfilled { count, finished });
+
+			Ok(Some(T::WeightInfo::backfill_attributes(count)).into())
+		}
+	}
+
+	#[pallet::tasks_experimental]
+	impl<T: Config> Pallet<T> {
+		/// Removes the reference of an account to a tag that was removed by a moderator, or that
+		/// is being destroyed.
+		#[pallet::task_index(0)]
+		#[pallet::task_condition(|tag_index, account| Pallet::<T>::is_stale_reference(*tag_index, account))]
+		#[pallet::task_list(
+			AccountsByTag::<T>::iter_keys()
+				.filter(|(tag_index, account)| Pallet::<T>::is_stale_reference(*tag_index, account))
+		)]
+		#[pallet::task_weight(T::WeightInfo::purge_tag_reference())]
+		pub fn purge_tag_reference(tag_index: u64, account: T::AccountId) -> DispatchResult {
+			AccountTags::<T>::remove(&account, tag_index);
+			AccountsByTag::<T>::remove(tag_index, &account);
+			Self::remove_reference(tag_index);
+
+			Self::deposit_event(Event::AccountUntagged { index: tag_index, account });
+
+			Ok(())
+		}
+
+		/// Finishes destroying a tag once it has no references left.
+		#[pallet::task_index(1)]
+		#[pallet::task_condition(|tag_index| Pallet::<T>::is_destruction_ready(*tag_index))]
+		#[pallet::task_list(
+			TagStatuses::<T>::iter_keys().filter(|tag_index| Pallet::<T>::is_destruction_ready(*tag_index))
+		)]
+		#[pallet::task_weight(T::WeightInfo::finish_tag_destruction())]
+		pub fn finish_tag_destruction(tag_index: u64) -> DispatchResult {
+			let (name, owner, deposit) = Self::get_removed_tag(tag_index)?;
+
+			Self::do_destroy_tag(tag_index, name, owner, deposit);
+
+			Ok(())
+		}
+
+		/// Removes an expired commitment, returning the deposit to the committer.
+		#[pallet::task_index(2)]
+		#[pallet::task_condition(|hash| Pallet::<T>::is_commitment_expired(hash))]
+		#[pallet::task_list(
+			Commitments::<T>::iter_keys().filter(|hash| Pallet::<T>::is_commitment_expired(hash))
+		)]
+		#[pallet::task_weight(T::WeightInfo::clear_expired_commitment())]
+		pub fn clear_expired_commitment(hash: T::Hash) -> DispatchResult {
+			let (committer, _committed_at, deposit) =
+				Commitments::<T>::take(hash).ok_or(Error::<T>::NoCommitment)?;
+
+			T::Currency::unreserve(&committer, deposit);
+
+			Self::deposit_event(Event::CommitmentCleared { hash, who: committer });
+
+			Ok(())
+		}
+
+		/// Removes a tag from an item that was burned, or whose tag was removed by a moderator or
+		/// is being destroyed.
+		#[pallet::task_index(3)]
+		#[pallet::task_condition(|tag_index, item| Pallet::<T>::is_stale_item_tag(*tag_index, item))]
+		#[pallet::task_list(
+			ItemsByTag::<T>::iter_keys()
+				.filter(|(tag_index, item)| Pallet::<T>::is_stale_item_tag(*tag_index, item))
+		)]
+		#[pallet::task_weight(T::WeightInfo::purge_item_tag())]
+		pub fn purge_item_tag(tag_index: u64, item: ItemOf<T>) -> DispatchResult {
+			if let Some((tagger, deposit)) = ItemsByTag::<T>::take(tag_index, item) {
+				T::Currency::unreserve(&tagger, deposit);
+			}
+			Self::forget_item_tag(tag_index, item);
+			Self::remove_reference(tag_index);
+
+			let (collection, item) = item;
+			Self::deposit_event(Event::ItemUntagged { index: tag_index, collection, item });
+
+			Ok(())
+		}
+
+		/// Removes a tag from a collection that was destroyed, or whose tag was removed by a
+		/// moderator or is being destroyed.
+		#[pallet::task_index(4)]
+		#[pallet::task_condition(|tag_index, collection| Pallet::<T>::is_stale_collection_tag(*tag_index, collection))]
+		#[pallet::task_list(
+			CollectionsByTag::<T>::iter_keys()
+				.filter(|(tag_index, collection)| Pallet::<T>::is_stale_collection_tag(*tag_index, collection))
+		)]
+		#[pallet::task_weight(T::WeightInfo::purge_collection_tag())]
+		pub fn purge_collection_tag(tag_index: u64, collection: T::CollectionId) -> DispatchResult {
+			CollectionsByTag::<T>::remove(tag_index, collection);
+			Self::forget_collection_tag(tag_index, collection);
+			Self::remove_reference(tag_index);
+
+			Self::deposit_event(Event::CollectionUntagged { index: tag_index, collection });
+
+			Ok(())
+		}
+
+		/// Returns the stake on a tag that was removed, destroyed or merged, or that is being
+		/// destroyed.
+		#[pallet::task_index(5)]
+		#[pallet::task_condition(|tag_index, staker| Pallet::<T>::is_stale_stake(*tag_index, staker))]
+		#[pallet::task_list(
+			Stakes::<T>::iter_keys().filter(|(tag_index, staker)| Pallet::<T>::is_stale_stake(*tag_index, staker))
+		)]
+		#[pallet::task_weight(T::WeightInfo::purge_stake())]
+		pub fn purge_stake(tag_index: u64, staker: T::AccountId) -> DispatchResult {
+			let amount = Stakes::<T>::take(tag_index, &staker).ok_or(Error::<T>::NotEnoughStake)?;
+			Self::release_stake(tag_index, staker, amount);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Creates a tag owned by `owner`, reserving the deposit from `deposit_payer`.
+		/// Returns the id of the new tag.
+		pub(crate) fn do_create_tag(
+			owner: T::AccountId,
+			name: BoundedVec<u8, T::TagNameLimit>,
+			deposit_payer: T::AccountId,
+		) -> Result<u64, DispatchError> {
+			// Amount to deposit. Comes from configuration but it's good practice to store any amount
 			// reserved at any point.
 			let deposit = T::TagDepositAmount::get();
 
+			// Names of merged tags are left pointing to their old id, and they are free again once
+			// the tag they resolve to is gone.
+			ensure!(
+				TagNames::<T>::get(&name).and_then(Self::resolve_tag).is_none(),
+				Error::<T>::NameTaken
+			);
+
 			// Try reserving the amount. This function naturally fails if the account lacks funds.
-			T::Currency::reserve(&who, deposit)?;
+			T::Currency::reserve(&deposit_payer, deposit)?;
 
 			// Get the next available index and update the counter
 			let index = TagIndex::<T>::get();
@@ -144,39 +2415,693 @@ pub mod pallet {
 				index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?
 			);
 
-			TagMap::<T>::insert(index, (name, who.clone(), deposit));
+			TagNames::<T>::insert(&name, index);
+			TagMap::<T>::insert(index, (name.clone(), owner.clone(), deposit));
+
+			Self::set_deposit_payer(index, &deposit_payer, &owner);
+
+			if let Some(collection) = TagCollection::<T>::get() {
+				let item = Self::tag_item_id(index).ok_or(Error::<T>::StorageOverflow)?;
+				// The item is minted to the payer so it pays its deposit, which is returned to it
+				// when the item is burned. The owner might not have any funds when the tag is
+				// relayed.
+				T::Nfts::mint_into(&collection, &item, &deposit_payer, &T::ItemConfig::default(), false)?;
+				if deposit_payer != owner {
+					T::Nfts::transfer(&collection, &item, &owner)?;
+				}
+			}
+
+			let created_at = Self::now();
+			TagActivities::<T>::insert(index, TagActivity {
+				created_at,
+				updated_at: created_at,
+				last_tagged_at: None,
+			});
+
+			T::OnTagLifecycle::on_created(index, &owner);
 
 			// Emit the corresponding event.
-			Self::deposit_event(Event::TagCreated {
-				index, who, deposit
+			Self::deposit_tag_event(index, &owner, Event::TagCreated {
+				index, who: owner.clone(), name, deposit, created_at
 			});
 
-			// Return a successful `DispatchResult`
-			Ok(())
+			Ok(index)
 		}
 
-		/// Destroys a tag from the chain
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::destroy_tag())]
-		pub fn destroy_tag(origin: OriginFor<T>, tag_index: u64) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			let who = ensure_signed(origin)?;
+		/// The event topic of a tag, for filtering the events that concern it.
+		pub fn tag_topic(tag_index: u64) -> T::Hash {
+			T::Hashing::hash_of(&(b"tags:tag", tag_index))
+		}
 
-			let (_name, creator, deposit) = TagMap::<T>::try_get(tag_index).map_err(|()|Error::<T>::InvalidTag)?;
+		/// The event topic of an account, for filtering the events of the tags it owns.
+		pub fn account_topic(who: &T::AccountId) -> T::Hash {
+			T::Hashing::hash_of(&(b"tags:account", who))
+		}
 
-			if who != creator {
-				Err(Error::<T>::NotAllowed)?;
-			}
+		/// Deposits an event indexed by the topics of the tag and its owner.
+		fn deposit_tag_event(tag_index: u64, owner: &T::AccountId, event: Event<T>) {
+			let topics = [Self::tag_topic(tag_index), Self::account_topic(owner)];
+			let event = <T as Config>::RuntimeEvent::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+		}
+
+		/// Removes a tag that is no longer in use, returning its deposit.
+		fn do_destroy_tag(
+			tag_index: u64,
+			name: BoundedVec<u8, T::TagNameLimit>,
+			owner: T::AccountId,
+			deposit: BalanceOf<T>,
+		) {
+			Self::release_tag(tag_index, &owner, deposit);
+			TagNames::<T>::remove(&name);
+			Self::bury_tag(tag_index, Some(owner.clone()), None);
+
+			T::OnTagLifecycle::on_destroyed(tag_index, &owner);
+
+			// Emit the corresponding event.
+			Self::deposit_tag_event(tag_index, &owner, Event::TagDestroyed {
+				index: tag_index, who: owner.clone(), name
+			});
+		}
 
+		/// Removes the data of a tag, except for its name, returning its deposit.
+		fn release_tag(tag_index: u64, owner: &T::AccountId, deposit: BalanceOf<T>) {
 			// Unreserve doesn't fail, unlike reserve
-			T::Currency::unreserve(&who, deposit);
+			T::Currency::unreserve(&Self::deposit_payer(tag_index, owner), deposit);
 
 			TagMap::<T>::remove(tag_index);
+			Self::burn_tag_item(tag_index);
+			TagDepositors::<T>::remove(tag_index);
+			Listings::<T>::remove(tag_index);
+			UsageFees::<T>::remove(tag_index);
+			TagScopes::<T>::remove(tag_index);
+			TotalStakes::<T>::remove(tag_index);
+			TagActivities::<T>::remove(tag_index);
+			TagStatuses::<T>::remove(tag_index);
+			Self::clear_translations(tag_index);
+		}
 
-			// Emit the corresponding event.
-			Self::deposit_event(Event::TagDestroyed { index: tag_index, who });
+		/// Moves up to `max` references from the tag `from` to the tag `into`, returning how
+		/// many were moved. Targets that already carry `into` just drop `from`, returning the
+		/// tagging deposit of items.
+		fn move_references(from: u64, into: u64, max: u32) -> u32 {
+			let mut moved = 0u32;
+			let mut added = 0u32;
+			for (account, ()) in AccountsByTag::<T>::drain_prefix(from).take(max as usize) {
+				AccountTags::<T>::remove(&account, from);
+				if !AccountTags::<T>::contains_key(&account, into) {
+					AccountTags::<T>::insert(&account, into, ());
+					AccountsByTag::<T>::insert(into, &account, ());
+					added += 1;
+				}
+				moved += 1;
+			}
+			for (collection, ()) in CollectionsByTag::<T>::drain_prefix(from).take((max - moved) as usize) {
+				if CollectionsByTag::<T>::contains_key(into, collection) {
+					Self::forget_collection_tag(from, collection);
+				} else {
+					TagsByCollection::<T>::remove(collection, from);
+					CollectionsByTag::<T>::insert(into, collection, ());
+					TagsByCollection::<T>::insert(collection, into, ());
+					added += 1;
+				}
+				moved += 1;
+			}
+			for (item, (tagger, deposit)) in ItemsByTag::<T>::drain_prefix(from).take((max - moved) as usize) {
+				Self::forget_item_tag(from, item);
+				if ItemsByTag::<T>::contains_key(into, item) {
+					T::Currency::unreserve(&tagger, deposit);
+				} else {
+					ItemTagCounts::<T>::mutate(item, |count| *count = count.saturating_add(1));
+					TaggedItemCounts::<T>::mutate(into, |count| *count = count.saturating_add(1));
+					ItemsByTag::<T>::insert(into, item, (tagger, deposit));
+					TagsByItem::<T>::insert(item, into, ());
+					// The item might have been burned, its tags are purged by a task then.
+					let _ = Self::mirror_item_tag(into, &item);
+					added += 1;
+				}
+				moved += 1;
+			}
+
+			TagReferences::<T>::mutate_exists(from, |count| {
+				*count = count.map(|count| count.saturating_sub(moved)).filter(|count| *count > 0);
+			});
+			TagReferences::<T>::mutate(into, |count| *count = count.saturating_add(added));
+			moved
+		}
+
+		/// Follows the aliases left by merges, returning the existing tag an id resolves to.
+		pub fn resolve_tag(tag_index: u64) -> Option<u64> {
+			let mut tag_index = tag_index;
+			// Merges only go into live tags, so aliases can't form cycles.
+			while !TagMap::<T>::contains_key(tag_index) {
+				tag_index = TagAliases::<T>::get(tag_index)?;
+			}
+			Some(tag_index)
+		}
+
+		/// The existing tag a name resolves to, following merges.
+		pub fn tag_by_name(name: &[u8]) -> Option<u64> {
+			let name = BoundedVec::<u8, T::TagNameLimit>::try_from(name.to_vec()).ok()?;
+			Self::resolve_tag(TagNames::<T>::get(name)?)
+		}
+
+		/// Reads a tag, telling destroyed ids apart from ones that never existed.
+		pub(crate) fn try_get_tag(
+			tag_index: u64,
+		) -> Result<(BoundedVec<u8, T::TagNameLimit>, T::AccountId, BalanceOf<T>), Error<T>> {
+			let (name, owner, deposit) =
+				TagMap::<T>::get(tag_index).ok_or_else(|| Self::missing_tag(tag_index))?;
+			Ok((name, Self::current_owner(tag_index, owner)?, deposit))
+		}
+
+		/// Gets a tag that is being removed. Its stored owner stands in for the holder of its item
+		/// once the item is burned.
+		fn get_removed_tag(
+			tag_index: u64,
+		) -> Result<(BoundedVec<u8, T::TagNameLimit>, T::AccountId, BalanceOf<T>), Error<T>> {
+			let (name, owner, deposit) =
+				TagMap::<T>::get(tag_index).ok_or_else(|| Self::missing_tag(tag_index))?;
+			let owner = Self::current_owner(tag_index, owner.clone()).unwrap_or(owner);
+			Ok((name, owner, deposit))
+		}
+
+		/// The owner of a tag given the one stored for it: the holder of its item if it's an NFT,
+		/// which fails once the item is burned.
+		fn current_owner(tag_index: u64, stored_owner: T::AccountId) -> Result<T::AccountId, Error<T>> {
+			if FirstTagItem::<T>::get().map_or(false, |first| tag_index >= first) {
+				Self::item_owner(tag_index).ok_or(Error::<T>::TagItemBurned)
+			} else {
+				Ok(stored_owner)
+			}
+		}
+
+		/// The account of the pallet, which owns the collection of tags.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// The id of the item a tag is minted as, `None` if item ids can't hold it.
+		fn tag_item_id(tag_index: u64) -> Option<T::ItemId> {
+			T::ItemId::try_from(tag_index).ok()
+		}
+
+		/// The holder of the item a tag is minted as, which owns the tag. `None` if tags aren't
+		/// NFTs, or the tag was created before they were, or its item was burned.
+		pub fn item_owner(tag_index: u64) -> Option<T::AccountId> {
+			T::Nfts::owner(&TagCollection::<T>::get()?, &Self::tag_item_id(tag_index)?)
+		}
+
+		/// Burns the item of a tag that is being removed, if it has one.
+		fn burn_tag_item(tag_index: u64) {
+			if let (Some(collection), Some(item)) =
+				(TagCollection::<T>::get(), Self::tag_item_id(tag_index))
+			{
+				// The item might have been burned by its holder already.
+				let _ = T::Nfts::burn(&collection, &item, None);
+			}
+		}
+
+		/// Remembers who paid the deposit of a tag. It's only needed when it isn't the owner,
+		/// unless tags are NFTs, whose owner can change outside of the pallet.
+		fn set_deposit_payer(tag_index: u64, payer: &T::AccountId, owner: &T::AccountId) {
+			if payer != owner || TagCollection::<T>::exists() {
+				TagDepositors::<T>::insert(tag_index, payer);
+			} else {
+				TagDepositors::<T>::remove(tag_index);
+			}
+		}
+
+		/// The error for a tag that isn't in `TagMap`.
+		fn missing_tag(tag_index: u64) -> Error<T> {
+			if Tombstones::<T>::contains_key(tag_index) {
+				Error::<T>::TagDestroyedAlready
+			} else {
+				Error::<T>::InvalidTag
+			}
+		}
+
+		/// Leaves a tombstone for a tag that was just removed.
+		fn bury_tag(tag_index: u64, destroyed_by: Option<T::AccountId>, successor: Option<u64>) {
+			Tombstones::<T>::insert(tag_index, Tombstone {
+				destroyed_by,
+				destroyed_at: frame_system::Pallet::<T>::block_number(),
+				successor,
+			});
+		}
+
+		/// The tombstone of a destroyed tag, `None` if the tag exists or never existed.
+		pub fn tombstone(tag_index: u64) -> Option<TombstoneOf<T>> {
+			Tombstones::<T>::get(tag_index)
+		}
+
+		/// The current block number and timestamp.
+		fn now() -> BlockTimeOf<T> {
+			BlockTime { block: frame_system::Pallet::<T>::block_number(), moment: T::Time::now() }
+		}
+
+		/// Records a change to the tag itself, returning when it happened.
+		fn touch_tag(tag_index: u64) -> BlockTimeOf<T> {
+			let now = Self::now();
+			TagActivities::<T>::mutate(tag_index, |activity| {
+				if let Some(activity) = activity {
+					activity.updated_at = now;
+				}
+			});
+			now
+		}
+
+		/// Records that the tag was applied to something, returning when it happened.
+		fn record_tagging(tag_index: u64) -> BlockTimeOf<T> {
+			let now = Self::now();
+			TagActivities::<T>::mutate(tag_index, |activity| {
+				if let Some(activity) = activity {
+					activity.last_tagged_at = Some(now);
+				}
+			});
+			now
+		}
+
+		/// Everything about a tag, or `None` if it doesn't exist.
+		pub fn tag_info(tag_index: u64) -> Option<TagInfoOf<T>> {
+			let (name, owner, deposit) = Self::try_get_tag(tag_index).ok()?;
+			Some(TagInfo {
+				name: name.into_inner(),
+				owner,
+				deposit,
+				status: TagStatuses::<T>::get(tag_index),
+				activity: TagActivities::<T>::get(tag_index).unwrap_or_default(),
+			})
+		}
+
+		/// The account the deposit of the tag was reserved from.
+		pub fn deposit_payer(tag_index: u64, owner: &T::AccountId) -> T::AccountId {
+			TagDepositors::<T>::get(tag_index).unwrap_or_else(|| owner.clone())
+		}
+
+		/// The name of the tag in the given language, or its canonical name if it has no
+		/// translation for it.
+		pub fn localized_name(tag_index: u64, lang: &[u8]) -> Option<Vec<u8>> {
+			let (name, _owner, _deposit) = TagMap::<T>::get(tag_index)?;
+
+			let translation = BoundedVec::<u8, T::LanguageCodeLimit>::try_from(lang.to_vec())
+				.ok()
+				.and_then(|lang| TagTranslations::<T>::get(tag_index, lang));
+
+			Some(match translation {
+				Some((text, _payer, _deposit)) => text.into_inner(),
+				None => name.into_inner(),
+			})
+		}
+
+		/// Removes all the translations of a tag, returning their deposits.
+		/// Bounded by `MaxTranslations`.
+		fn clear_translations(tag_index: u64) {
+			for (_lang, (_text, payer, deposit)) in TagTranslations::<T>::drain_prefix(tag_index) {
+				T::Currency::unreserve(&payer, deposit);
+			}
+			TranslationCounts::<T>::remove(tag_index);
+		}
+
+		/// Whether the account has a stake on a tag that was removed or is being destroyed.
+		fn is_stale_stake(tag_index: u64, staker: &T::AccountId) -> bool {
+			Stakes::<T>::contains_key(tag_index, staker) &&
+				(!TagMap::<T>::contains_key(tag_index) ||
+					TagStatuses::<T>::get(tag_index) == TagStatus::Destroying)
+		}
+
+		/// Returns a stake taken out of `Stakes` to its staker without unbonding.
+		fn release_stake(tag_index: u64, staker: T::AccountId, amount: BalanceOf<T>) {
+			T::Currency::unreserve(&staker, amount);
+			Self::reduce_total_stake(tag_index, amount);
+
+			Self::deposit_event(Event::StakeReleased { index: tag_index, who: staker, amount });
+		}
+
+		/// Takes `amount` out of the total stake on a tag.
+		fn reduce_total_stake(tag_index: u64, amount: BalanceOf<T>) {
+			TotalStakes::<T>::mutate_exists(tag_index, |total| {
+				*total = total.map(|total| total.saturating_sub(amount)).filter(|total| !total.is_zero());
+			});
+		}
+
+		/// Up to `limit` live tags with the most funds staked on them, from the most staked, along
+		/// with their total stake. Tags without stake are left out.
+		pub fn top_tags_by_stake(limit: u32) -> Vec<(u64, BalanceOf<T>)> {
+			let mut tags: Vec<_> = TotalStakes::<T>::iter()
+				.filter(|(tag_index, _)| TagStatuses::<T>::get(tag_index) == TagStatus::Live)
+				.collect();
+			tags.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+			tags.truncate(limit as usize);
+			tags
+		}
+
+		/// Whether the account carries a tag that was removed or is being destroyed.
+		fn is_stale_reference(tag_index: u64, account: &T::AccountId) -> bool {
+			AccountsByTag::<T>::contains_key(tag_index, account) &&
+				(!TagMap::<T>::contains_key(tag_index) ||
+					TagStatuses::<T>::get(tag_index) == TagStatus::Destroying)
+		}
+
+		/// Removes the index entries of a tag on an item, except for `ItemsByTag` so that it can
+		/// be drained.
+		fn forget_item_tag(tag_index: u64, item: ItemOf<T>) {
+			if T::MirrorToAttributes::get() {
+				// The item might have been burned, or tagged before mirroring was enabled.
+				let _ = T::Nfts::clear_attribute(&item.0, &item.1, &Self::attribute_key(tag_index));
+			}
+			TagsByItem::<T>::remove(item, tag_index);
+			ItemTagCounts::<T>::mutate_exists(item, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+			TaggedItemCounts::<T>::mutate_exists(tag_index, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// Fails with `OutOfScope` if the tag has a scope without `collection`.
+		fn ensure_in_scope(tag_index: u64, collection: &T::CollectionId) -> DispatchResult {
+			if let Some(scope) = TagScopes::<T>::get(tag_index) {
+				ensure!(scope.contains(collection), Error::<T>::OutOfScope);
+			}
+			Ok(())
+		}
+
+		/// Applies a live tag to an existing item, once the tagger is known to be allowed to,
+		/// reserving the tagging deposit from it and charging the usage fee of the tag, up to
+		/// `max_fee`.
+		pub(crate) fn do_tag_item(
+			tag_index: u64,
+			item: ItemOf<T>,
+			tagger: &T::AccountId,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index)?;
+			ensure!(TagStatuses::<T>::get(tag_index) == TagStatus::Live, Error::<T>::TagDestroying);
+			Self::ensure_in_scope(tag_index, &item.0)?;
+			ensure!(!ItemsByTag::<T>::contains_key(tag_index, item), Error::<T>::AlreadyTagged);
+
+			if let Some(fee) = UsageFees::<T>::get(tag_index).filter(|_| *tagger != owner) {
+				ensure!(fee <= max_fee, Error::<T>::FeeAboveLimit);
+				T::Currency::transfer(tagger, &owner, fee, ExistenceRequirement::KeepAlive)?;
+				Self::deposit_event(Event::FeePaid { index: tag_index, who: tagger.clone(), owner, fee });
+			}
+
+			ItemTagCounts::<T>::try_mutate(item, |count| -> DispatchResult {
+				ensure!(*count < T::MaxTagsPerItem::get(), Error::<T>::TooManyTags);
+				*count += 1;
+				Ok(())
+			})?;
+			let deposit = T::TaggingDeposit::get();
+			T::Currency::reserve(tagger, deposit)?;
+			Self::add_reference(tag_index)?;
+			ItemsByTag::<T>::insert(tag_index, item, (tagger.clone(), deposit));
+			TagsByItem::<T>::insert(item, tag_index, ());
+			TaggedItemCounts::<T>::mutate(tag_index, |count| *count = count.saturating_add(1));
+			Self::mirror_item_tag(tag_index, &item)?;
+
+			let tagged_at = Self::record_tagging(tag_index);
+
+			let (collection, item) = item;
+			Self::deposit_event(Event::ItemTagged {
+				index: tag_index, collection, item, who: tagger.clone(), deposit, tagged_at
+			});
 
 			Ok(())
 		}
+
+		/// Removes the index entries of a tag on a collection, except for `CollectionsByTag` so
+		/// that it can be drained.
+		fn forget_collection_tag(tag_index: u64, collection: T::CollectionId) {
+			TagsByCollection::<T>::remove(collection, tag_index);
+			CollectionTagCounts::<T>::mutate_exists(collection, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// What `who` pays to apply a live tag to an item: the tagging deposit, which is returned
+		/// when the tag is removed, plus the usage fee of the tag unless `who` owns it.
+		pub fn tagging_cost(tag_index: u64, who: &T::AccountId) -> Option<BalanceOf<T>> {
+			let (_name, owner, _deposit) = Self::try_get_tag(tag_index).ok()?;
+			if TagStatuses::<T>::get(tag_index) != TagStatus::Live {
+				return None;
+			}
+			let fee = UsageFees::<T>::get(tag_index).filter(|_| *who != owner).unwrap_or_default();
+			Some(T::TaggingDeposit::get().saturating_add(fee))
+		}
+
+		/// The existing tags of an item, both its own and the ones inherited from its collection,
+		/// sorted by id.
+		pub fn effective_tags(item: ItemOf<T>) -> Vec<u64> {
+			let mut tags: Vec<u64> = TagsByItem::<T>::iter_key_prefix(item)
+				.chain(TagsByCollection::<T>::iter_key_prefix(item.0))
+				.filter(|tag_index| TagMap::<T>::contains_key(tag_index))
+				.collect();
+			tags.sort_unstable();
+			tags.dedup();
+			tags
+		}
+
+		/// Writes the name of a tag to the attributes of an item, if mirroring is enabled.
+		fn mirror_item_tag(tag_index: u64, item: &ItemOf<T>) -> DispatchResult {
+			if !T::MirrorToAttributes::get() {
+				return Ok(());
+			}
+			let (name, _owner, _deposit) = Self::try_get_tag(tag_index)?;
+			T::Nfts::set_attribute(&item.0, &item.1, &Self::attribute_key(tag_index), &name)
+		}
+
+		/// The bytes the signer of a pre-signed tag signs: the signing context of the pallet,
+		/// the genesis hash of the chain and the payload, SCALE encoded.
+		pub fn signed_message(payload: &PreSignedTagOf<T>) -> Vec<u8> {
+			let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			(PRE_SIGNED_TAG_CONTEXT, genesis_hash, payload).encode()
+		}
+
+		/// The key of the item attribute that mirrors a tag, `tag:` followed by the tag id in
+		/// decimal.
+		pub fn attribute_key(tag_index: u64) -> Vec<u8> {
+			let mut digits = Vec::new();
+			let mut rest = tag_index;
+			loop {
+				digits.push(b'0' + (rest % 10) as u8);
+				rest /= 10;
+				if rest == 0 {
+					break;
+				}
+			}
+			let mut key = b"tag:".to_vec();
+			key.extend(digits.iter().rev());
+			key
+		}
+
+		/// Items the tag is applied to, in storage order, starting after `cursor`.
+		/// The last item of a page is the cursor of the next one.
+		pub fn items_by_tag(tag_index: u64, cursor: Option<ItemOf<T>>, limit: u32) -> Vec<ItemOf<T>> {
+			Self::tagged_items(tag_index, cursor).take(limit as usize).collect()
+		}
+
+		/// Items carrying every tag in `include` and none in `exclude`. Scans up to `limit` items
+		/// of the included tag with the fewest items, starting after `cursor`, and returns the
+		/// ones that match along with the cursor of the next page, or `None` once every item was
+		/// scanned. `None` if `include` is empty or a list has more than `MaxQueryTags` tags.
+		///
+		/// Only the tags applied to the items themselves are considered, not the ones inherited
+		/// from their collections.
+		pub fn items_with_all_tags(
+			include: &[u64],
+			exclude: &[u64],
+			cursor: Option<ItemOf<T>>,
+			limit: u32,
+		) -> Option<(Vec<ItemOf<T>>, Option<ItemOf<T>>)> {
+			Self::check_query(include, exclude)?;
+			let smallest = *include.iter().min_by_key(|tag| TaggedItemCounts::<T>::get(*tag))?;
+
+			Some(Self::scan_items(Self::tagged_items(smallest, cursor), limit, |item| {
+				include.iter().all(|tag| *tag == smallest || ItemsByTag::<T>::contains_key(tag, item)) &&
+					!exclude.iter().any(|tag| ItemsByTag::<T>::contains_key(tag, item))
+			}))
+		}
+
+		/// Items carrying any tag in `include` and none in `exclude`. Scans up to `limit` items
+		/// carrying the included tags, starting after `cursor`, and returns the ones that match
+		/// along with the cursor of the next page, or `None` once every item was scanned. `None`
+		/// if `include` is empty or a list has more than `MaxQueryTags` tags.
+		///
+		/// Only the tags applied to the items themselves are considered, not the ones inherited
+		/// from their collections.
+		pub fn items_with_any_tag(
+			include: &[u64],
+			exclude: &[u64],
+			cursor: Option<ItemOf<T>>,
+			limit: u32,
+		) -> Option<(Vec<ItemOf<T>>, Option<ItemOf<T>>)> {
+			Self::check_query(include, exclude)?;
+
+			// The items of every tag come in the same order, that of the hash of the item, so
+			// merging them yields each item once, and any item works as a cursor for all of them.
+			let mut sources: Vec<_> = include.iter()
+				.map(|tag| Self::tagged_items(*tag, cursor).peekable())
+				.collect();
+			let items = sp_std::iter::from_fn(move || {
+				let next = sources.iter_mut()
+					.filter_map(|source| source.peek().copied())
+					.min_by_key(|item| Blake2_128Concat::hash(&item.encode()))?;
+				for source in sources.iter_mut() {
+					source.next_if_eq(&next);
+				}
+				Some(next)
+			});
+
+			Some(Self::scan_items(items, limit, |item| {
+				!exclude.iter().any(|tag| ItemsByTag::<T>::contains_key(tag, item))
+			}))
+		}
+
+		/// Checks the tag lists of an item query: some tag to include, and at most `MaxQueryTags`
+		/// tags in each list.
+		fn check_query(include: &[u64], exclude: &[u64]) -> Option<()> {
+			let max = T::MaxQueryTags::get() as usize;
+			(!include.is_empty() && include.len() <= max && exclude.len() <= max).then_some(())
+		}
+
+		/// Items the tag is applied to, in storage order, starting after `cursor`.
+		fn tagged_items(tag_index: u64, cursor: Option<ItemOf<T>>) -> impl Iterator<Item = ItemOf<T>> {
+			match cursor {
+				Some(cursor) => ItemsByTag::<T>::iter_key_prefix_from(
+					tag_index,
+					ItemsByTag::<T>::hashed_key_for(tag_index, cursor),
+				),
+				None => ItemsByTag::<T>::iter_key_prefix(tag_index),
+			}
+		}
+
+		/// Takes up to `limit` items, keeping the ones that match. Returns them along with the
+		/// last item taken if there are more left.
+		fn scan_items(
+			items: impl Iterator<Item = ItemOf<T>>,
+			limit: u32,
+			matches: impl Fn(&ItemOf<T>) -> bool,
+		) -> (Vec<ItemOf<T>>, Option<ItemOf<T>>) {
+			let mut found = Vec::new();
+			let mut last = None;
+			for (scanned, item) in items.enumerate() {
+				if scanned == limit as usize {
+					return (found, last);
+				}
+				if matches(&item) {
+					found.push(item);
+				}
+				last = Some(item);
+			}
+			(found, None)
+		}
+
+		/// Tags applied to the item, in storage order, starting after `cursor`.
+		/// The last tag of a page is the cursor of the next one.
+		pub fn tags_by_item(item: ItemOf<T>, cursor: Option<u64>, limit: u32) -> Vec<u64> {
+			let tags = match cursor {
+				Some(cursor) => TagsByItem::<T>::iter_key_prefix_from(
+					item,
+					TagsByItem::<T>::hashed_key_for(item, cursor),
+				),
+				None => TagsByItem::<T>::iter_key_prefix(item),
+			};
+			tags.take(limit as usize).collect()
+		}
+
+		/// Whether the item carries a tag, and either the item was burned or the tag was removed
+		/// or is being destroyed.
+		fn is_stale_item_tag(tag_index: u64, item: &ItemOf<T>) -> bool {
+			ItemsByTag::<T>::contains_key(tag_index, item) &&
+				(T::Nfts::owner(&item.0, &item.1).is_none() ||
+					!TagMap::<T>::contains_key(tag_index) ||
+					TagStatuses::<T>::get(tag_index) == TagStatus::Destroying)
+		}
+
+		/// Whether the collection carries a tag, and either the collection was destroyed or the
+		/// tag was removed or is being destroyed.
+		fn is_stale_collection_tag(tag_index: u64, collection: &T::CollectionId) -> bool {
+			CollectionsByTag::<T>::contains_key(tag_index, collection) &&
+				(T::Nfts::collection_owner(collection).is_none() ||
+					!TagMap::<T>::contains_key(tag_index) ||
+					TagStatuses::<T>::get(tag_index) == TagStatus::Destroying)
+		}
+
+		/// Whether a tag being destroyed has no references left.
+		fn is_destruction_ready(tag_index: u64) -> bool {
+			TagStatuses::<T>::get(tag_index) == TagStatus::Destroying &&
+				TagReferences::<T>::get(tag_index) == 0
+		}
+
+		/// Whether a commitment exists and is too old to be revealed.
+		fn is_commitment_expired(hash: &T::Hash) -> bool {
+			Commitments::<T>::get(hash).map_or(false, |(_committer, committed_at, _deposit)| {
+				frame_system::Pallet::<T>::block_number() >
+					committed_at.saturating_add(T::MaxCommitmentAge::get())
+			})
+		}
+
+		/// Whether the account carries the tag, and the tag still exists and is live.
+		pub fn has_tag(who: &T::AccountId, tag_index: u64) -> bool {
+			AccountTags::<T>::contains_key(who, tag_index) &&
+				TagMap::<T>::contains_key(tag_index) &&
+				TagStatuses::<T>::get(tag_index) == TagStatus::Live
+		}
+
+		/// Counts a new target the tag is applied to.
+		fn add_reference(tag_index: u64) -> DispatchResult {
+			TagReferences::<T>::try_mutate(tag_index, |count| {
+				*count = count.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+				Ok(())
+			})
+		}
+
+		/// Forgets a target the tag was applied to.
+		fn remove_reference(tag_index: u64) {
+			TagReferences::<T>::mutate_exists(tag_index, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+		}
+	}
+}
+
+/// Ensures the origin is a signed account that carries the tag `TagId`, so tags can be used as
+/// roles. Succeeds with the account id.
+pub struct EnsureTagged<T, TagId>(PhantomData<(T, TagId)>);
+
+impl<T: Config, TagId: Get<u64>> EnsureOrigin<T::RuntimeOrigin> for EnsureTagged<T, TagId> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		let o: Result<RawOrigin<T::AccountId>, T::RuntimeOrigin> = o.into();
+		o.and_then(|o| match o {
+			RawOrigin::Signed(who) if Pallet::<T>::has_tag(&who, TagId::get()) => Ok(who),
+			r => Err(T::RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		// The tag must already exist, its owner is given the role.
+		let tag_index = TagId::get();
+		let (_name, owner, _deposit) = TagMap::<T>::get(tag_index).ok_or(())?;
+		AccountTags::<T>::insert(&owner, tag_index, ());
+		AccountsByTag::<T>::insert(tag_index, &owner, ());
+		Ok(RawOrigin::Signed(owner).into())
+	}
+}
+
+/// A call filter for runtimes where every name goes through `commit_tag` and `reveal_tag`. It
+/// rejects `create_tag` and `create_tag_with_signature`, whose names are in plain text in the
+/// transaction pool and could take a name before its reveal, and lets every other call through.
+pub struct RevealedNamesOnly<T>(PhantomData<T>);
+
+impl<T: Config, Call: IsSubType<pallet::Call<T>>> Contains<Call> for RevealedNamesOnly<T> {
+	fn contains(call: &Call) -> bool {
+		!matches!(
+			call.is_sub_type(),
+			Some(pallet::Call::create_tag { .. } | pallet::Call::create_tag_with_signature { .. })
+		)
 	}
 }
@@ -2,16 +2,66 @@ use crate as pallet_tags;
 
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Currency, EitherOfDiverse, OnUnbalanced},
+	PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_nfts::PalletFeatures;
 use sp_core::H256;
 use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
+/// A pallet that follows the lifecycle of tags, used to test the `OnTagLifecycle` hooks.
+#[frame_support::pallet(dev_mode)]
+pub mod pallet_tag_guard {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	/// Tags that exist according to the hooks, with their owner.
+	#[pallet::storage]
+	pub type Known<T: Config> = StorageMap<_, Blake2_128Concat, u64, T::AccountId, OptionQuery>;
+
+	/// Tags this pallet refuses to let go of.
+	#[pallet::storage]
+	pub type Protected<T> = StorageMap<_, Blake2_128Concat, u64, (), OptionQuery>;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The tag is protected and can't be destroyed.
+		Protected,
+	}
+
+	impl<T: Config> crate::OnTagLifecycle<T::AccountId> for Pallet<T> {
+		fn on_created(tag: u64, owner: &T::AccountId) {
+			Known::<T>::insert(tag, owner);
+		}
+
+		fn can_destroy(tag: u64, _owner: &T::AccountId) -> DispatchResult {
+			ensure!(!Protected::<T>::contains_key(tag), Error::<T>::Protected);
+			Ok(())
+		}
+
+		fn on_destroyed(tag: u64, _owner: &T::AccountId) {
+			Known::<T>::remove(tag);
+			Protected::<T>::remove(tag);
+		}
+
+		fn on_transferred(tag: u64, _from: &T::AccountId, to: &T::AccountId) {
+			Known::<T>::insert(tag, to);
+		}
+	}
+}
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test
@@ -19,6 +69,9 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		TagModule: pallet_tags,
 		Balances: pallet_balances,
+		TagGuard: pallet_tag_guard,
+		Nfts: pallet_nfts,
+		Timestamp: pallet_timestamp,
 	}
 );
 
@@ -68,9 +121,86 @@ impl pallet_balances::Config for Test {
 	type RuntimeFreezeReason = ();
 }
 
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub NftsFeatures: PalletFeatures = PalletFeatures::all_enabled();
+	pub storage NftsItemDeposit: Balance = 0;
+}
+
+/// NFTs without deposits unless a test sets the item deposit, so they don't get in the way of the
+/// tag deposits.
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Locker = ();
+	type CollectionDeposit = ConstU64<0>;
+	type ItemDeposit = NftsItemDeposit;
+	type MetadataDepositBase = ConstU64<0>;
+	type AttributeDepositBase = ConstU64<0>;
+	type DepositPerByte = ConstU64<0>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type Features = NftsFeatures;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
 parameter_types! {
 	pub const TagNameLimit: u32 = 256;
 	pub const TagDepositAmount: Balance = 1;
+	pub const ReportBond: Balance = 2;
+	pub const ReportReasonLimit: u32 = 128;
+	pub const ModeratorTag: u64 = 0;
+	pub const CommitmentDeposit: Balance = 1;
+	pub const MinCommitmentAge: u64 = 2;
+	pub const MaxCommitmentAge: u64 = 10;
+	pub const LanguageCodeLimit: u32 = 8;
+	pub const TranslationLimit: u32 = 64;
+	pub const MaxTranslations: u32 = 2;
+	pub const TranslationDepositPerByte: Balance = 1;
+	pub const RemoveReferencesLimit: u32 = 2;
+	pub const MaxTagsPerItem: u32 = 2;
+	pub storage MirrorToAttributes: bool = false;
+	pub storage TaggingMode: pallet_tags::TaggingPolicy = pallet_tags::TaggingPolicy::TagOwner;
+	pub const ProposalDeposit: Balance = 3;
+	pub const TaggingDeposit: Balance = 1;
+	pub storage SlashRemovedTaggings: bool = false;
+	pub const MaxUsageFee: Balance = 10;
+	pub const MaxQueryTags: u32 = 3;
+	pub const TagsPalletId: PalletId = PalletId(*b"py/tags_");
+	pub const MaxScopeCollections: u32 = 2;
+	pub const StakeUnbondingPeriod: u64 = 5;
+	pub const MaxUnbondingChunks: u32 = 2;
+	pub const MinStake: Balance = 2;
+}
+
+/// Account that receives the funds slashed by moderation, standing in for a treasury.
+pub const TREASURY: u64 = 99;
+
+pub struct SlashToTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for SlashToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		Balances::resolve_creating(&TREASURY, amount);
+	}
 }
 
 impl pallet_tags::Config for Test {
@@ -80,6 +210,73 @@ impl pallet_tags::Config for Test {
 
 	type TagNameLimit = TagNameLimit;
 	type TagDepositAmount = TagDepositAmount;
+
+	/// Accounts tagged with the moderator tag can moderate, just like root.
+	type ModeratorOrigin = EitherOfDiverse<EnsureRoot<u64>, pallet_tags::EnsureTagged<Test, ModeratorTag>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Slash = SlashToTreasury;
+	type ReportBond = ReportBond;
+	type ReportReasonLimit = ReportReasonLimit;
+	type OnTagLifecycle = TagGuard;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type CommitmentDeposit = CommitmentDeposit;
+	type MinCommitmentAge = MinCommitmentAge;
+	type MaxCommitmentAge = MaxCommitmentAge;
+	type LanguageCodeLimit = LanguageCodeLimit;
+	type TranslationLimit = TranslationLimit;
+	type MaxTranslations = MaxTranslations;
+	type TranslationDepositPerByte = TranslationDepositPerByte;
+	type RemoveReferencesLimit = RemoveReferencesLimit;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Nfts = Nfts;
+	type MaxTagsPerItem = MaxTagsPerItem;
+	type ItemConfig = pallet_nfts::ItemConfig;
+	type MirrorToAttributes = MirrorToAttributes;
+	type TagPermission = pallet_tags::FixedPolicy<TaggingMode>;
+	type ProposalDeposit = ProposalDeposit;
+	type TaggingDeposit = TaggingDeposit;
+	type SlashRemovedTaggings = SlashRemovedTaggings;
+	type MaxUsageFee = MaxUsageFee;
+	type Time = Timestamp;
+	type MaxQueryTags = MaxQueryTags;
+	type CollectionConfig = pallet_nfts::CollectionConfigFor<Test>;
+	type PalletId = TagsPalletId;
+	type MaxScopeCollections = MaxScopeCollections;
+	type StakeUnbondingPeriod = StakeUnbondingPeriod;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
+	type MinStake = MinStake;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_tags::BenchmarkHelper<UintAuthorityId, TestSignature, u64, u32, u32> for MockBenchmarkHelper {
+	fn create_signer() -> UintAuthorityId {
+		UintAuthorityId(100)
+	}
+
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+
+	fn create_item(owner: &u64) -> (u32, u32) {
+		(create_item(*owner), 0)
+	}
+}
+
+impl pallet_tag_guard::Config for Test {}
+
+/// Creates a collection owned by `owner` and mints item 0 to it, returning the collection id.
+pub fn create_item(owner: u64) -> u32 {
+	let collection = pallet_nfts::NextCollectionId::<Test>::get().unwrap_or_default();
+	Nfts::force_create(RuntimeOrigin::root(), owner, Default::default()).unwrap();
+	Nfts::mint(RuntimeOrigin::signed(owner), collection, 0, owner, None).unwrap();
+	collection
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,7 +1,27 @@
-use crate::{mock::*, Error, Event, TagIndex, TagMap};
-use frame_support::{assert_ok, assert_noop, traits::Currency};
+use crate::{
+	mock::*, Task, AccountTags, Commitments, EnsureTagged, Error, Event, Listings, PreSignedTag, Reports,
+	SignerNonces, TagDepositors, TagIndex, TagMap, TagNames, TagReferences, TagTranslations,
+	TranslationCounts, AccountsByTag, TagStatus, TagStatuses, ItemsByTag, TagsByItem, ItemTagCounts,
+	AttributeBackfill, CollectionsByTag, TagsByCollection, CollectionTagCounts, TagProposals,
+	TaggingPolicy, UsageFees, TagAliases, TagActivities, BlockTime, TaggedItemCounts, Tombstone, TagCollection,
+	TagScopes, Stakes, TotalStakes, Unbonding, RevealedNamesOnly,
+};
+use codec::Encode;
+use frame_support::{
+	assert_ok, assert_noop,
+	traits::{tokens::nonfungibles_v2::Destroy, ConstU64, Contains, Currency, EnsureOrigin, ReservableCurrency, Task as _},
+};
+use sp_runtime::{
+	generic::Header,
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
 use sp_core::bounded::BoundedVec;
 
+/// When the events of most tests happen.
+const START: BlockTime<u64, u64> = BlockTime { block: 1, moment: 0 };
+
 macro_rules! bvec {
 	($( $x:tt )*) => {
 		TryInto::<BoundedVec<_, _>>::try_into(vec![$( $x )*]).unwrap()
@@ -33,7 +53,7 @@ fn create_and_destroy_tag() {
 		));
 
 		// Ensure the generated event matches
-		System::assert_last_event(Event::TagCreated { index: tag_index, who: 1, deposit }.into());
+		System::assert_last_event(Event::TagCreated { index: tag_index, who: 1, name: name.clone(), deposit, created_at: START }.into());
 
 		// Assert the index advanced
 		assert_eq!(TagIndex::<Test>::get(), tag_index + 1);
@@ -43,7 +63,18 @@ fn create_and_destroy_tag() {
 
 		// Ensure the storage was modified and the event was emitted
 		assert_eq!(TagMap::<Test>::try_get(tag_index), Err(()));
-		System::assert_last_event(Event::TagDestroyed { index: tag_index, who: 1 }.into());
+		System::assert_last_event(Event::TagDestroyed { index: tag_index, who: 1, name: name.clone() }.into());
+
+		// Both events can be found by the topics of the tag and its owner
+		let topics = vec![TagModule::tag_topic(tag_index), TagModule::account_topic(&1)];
+		let indexed: Vec<_> = System::events().into_iter()
+			.filter(|record| record.topics == topics)
+			.map(|record| record.event)
+			.collect();
+		assert_eq!(indexed, vec![
+			Event::TagCreated { index: tag_index, who: 1, name: name.clone(), deposit, created_at: START }.into(),
+			Event::TagDestroyed { index: tag_index, who: 1, name: name.clone() }.into(),
+		]);
 	});
 }
 
@@ -63,7 +94,7 @@ fn destroy_tag_by_owner() {
 
 		// Create tag
 		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), name.clone()));
-		System::assert_last_event(Event::TagCreated { index: tag_index, who: 1, deposit }.into());
+		System::assert_last_event(Event::TagCreated { index: tag_index, who: 1, name: name.clone(), deposit, created_at: START }.into());
 
 		// Try to destroy with another user
 		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(2), tag_index), Error::<Test>::NotAllowed);
@@ -73,7 +104,7 @@ fn destroy_tag_by_owner() {
 
 		// Ensure the storage was modified and the event was emitted
 		assert_eq!(TagMap::<Test>::try_get(tag_index), Err(()));
-		System::assert_last_event(Event::TagDestroyed { index: tag_index, who: 1 }.into());
+		System::assert_last_event(Event::TagDestroyed { index: tag_index, who: 1, name: name.clone() }.into());
 	});
 }
 
@@ -91,4 +122,2072 @@ fn destroy_invalid_tag() {
 		// Try to destroy a tag that has never been created
 		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), tag_index), Error::<Test>::InvalidTag);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn report_and_uphold() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let deposit = TagDepositAmount::get();
+		let bond = ReportBond::get();
+		let reason = bvec![66, 65, 68];
+		let tag_index = 0;
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+
+		// Report the tag, the bond is reserved from the reporter
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), tag_index, reason.clone()));
+		assert_eq!(Reports::<Test>::get(tag_index), Some((2, reason.clone(), bond)));
+		assert_eq!(Balances::reserved_balance(&2), bond);
+		System::assert_last_event(Event::TagReported { index: tag_index, reporter: 2, reason, bond }.into());
+
+		// The owner can't destroy the tag while it is under review
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), tag_index), Error::<Test>::UnderReview);
+
+		// Only the moderator origin can rule on the report
+		assert_noop!(TagModule::uphold_report(RuntimeOrigin::signed(2), tag_index), DispatchError::BadOrigin);
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), tag_index));
+
+		// The tag is gone, the deposit went to the treasury and the reporter got the bond back
+		assert_eq!(TagMap::<Test>::get(tag_index), None);
+		assert_eq!(Reports::<Test>::get(tag_index), None);
+		assert_eq!(Balances::total_balance(&1), 100 - deposit);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&2), 100);
+		assert_eq!(Balances::free_balance(&TREASURY), deposit);
+		System::assert_last_event(Event::ReportUpheld { index: tag_index, reporter: 2, owner: 1, slashed: deposit }.into());
+	});
+}
+
+#[test]
+fn report_and_dismiss() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let bond = ReportBond::get();
+		let tag_index = 0;
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), tag_index, bvec![]));
+
+		// Dismiss the report, the bond of the reporter goes to the treasury
+		assert_ok!(TagModule::dismiss_report(RuntimeOrigin::root(), tag_index));
+		assert_eq!(Reports::<Test>::get(tag_index), None);
+		assert_eq!(Balances::total_balance(&2), 100 - bond);
+		assert_eq!(Balances::free_balance(&TREASURY), bond);
+		System::assert_last_event(Event::ReportDismissed { index: tag_index, reporter: 2, slashed: bond }.into());
+
+		// The tag survives and its owner can destroy it again
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), tag_index));
+	});
+}
+
+#[test]
+fn report_errors() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		let tag_index = 0;
+
+		// Reports need an existing tag
+		assert_noop!(TagModule::report_tag(RuntimeOrigin::signed(2), tag_index, bvec![]), Error::<Test>::InvalidTag);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), tag_index, bvec![]));
+
+		// Only one report can be open at a time
+		assert_noop!(TagModule::report_tag(RuntimeOrigin::signed(3), tag_index, bvec![]), Error::<Test>::AlreadyReported);
+		assert_eq!(Balances::reserved_balance(&3), 0);
+
+		// Ruling twice on the same report fails
+		assert_ok!(TagModule::dismiss_report(RuntimeOrigin::root(), tag_index));
+		assert_noop!(TagModule::dismiss_report(RuntimeOrigin::root(), tag_index), Error::<Test>::NotReported);
+		assert_noop!(TagModule::uphold_report(RuntimeOrigin::root(), tag_index), Error::<Test>::NotReported);
+	});
+}
+
+#[test]
+fn lifecycle_hooks() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		// The hooks are notified of new tags
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert!(pallet_tag_guard::Known::<Test>::contains_key(0));
+		assert!(pallet_tag_guard::Known::<Test>::contains_key(1));
+
+		// A protected tag can't be destroyed by its owner
+		pallet_tag_guard::Protected::<Test>::insert(0, ());
+		assert_noop!(
+			TagModule::destroy_tag(RuntimeOrigin::signed(1), 0),
+			pallet_tag_guard::Error::<Test>::Protected
+		);
+
+		// Once unprotected it can, and the hooks are notified
+		pallet_tag_guard::Protected::<Test>::remove(0);
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert!(!pallet_tag_guard::Known::<Test>::contains_key(0));
+
+		// Moderation ignores the veto, but still notifies the hooks
+		pallet_tag_guard::Protected::<Test>::insert(1, ());
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 1, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 1));
+		assert!(!pallet_tag_guard::Known::<Test>::contains_key(1));
+		assert!(!pallet_tag_guard::Protected::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn tag_and_untag_account() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let tag_index = 0;
+
+		// Tagging needs an existing tag
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), tag_index, 2), Error::<Test>::InvalidTag);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+
+		// Only the owner of the tag can grant it
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(2), tag_index, 2), Error::<Test>::NotAllowed);
+
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), tag_index, 2));
+		assert!(AccountTags::<Test>::contains_key(2, tag_index));
+		assert!(AccountsByTag::<Test>::contains_key(tag_index, 2));
+		assert_eq!(TagReferences::<Test>::get(tag_index), 1);
+		System::assert_last_event(Event::AccountTagged { index: tag_index, account: 2, tagged_at: START }.into());
+
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), tag_index, 2), Error::<Test>::AlreadyTagged);
+
+		// The tag can't be destroyed while an account carries it
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), tag_index), Error::<Test>::TagInUse);
+
+		// Other accounts can't remove the tag
+		assert_noop!(TagModule::untag_account(RuntimeOrigin::signed(3), tag_index, 2), Error::<Test>::NotAllowed);
+
+		// The tagged account can drop the tag by itself
+		assert_ok!(TagModule::untag_account(RuntimeOrigin::signed(2), tag_index, 2));
+		assert!(!AccountTags::<Test>::contains_key(2, tag_index));
+		assert!(!AccountsByTag::<Test>::contains_key(tag_index, 2));
+		assert_eq!(TagReferences::<Test>::get(tag_index), 0);
+		System::assert_last_event(Event::AccountUntagged { index: tag_index, account: 2 }.into());
+
+		assert_noop!(TagModule::untag_account(RuntimeOrigin::signed(1), tag_index, 2), Error::<Test>::NotTagged);
+
+		// Now the tag can be destroyed
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), tag_index));
+	});
+}
+
+#[test]
+fn ensure_tagged_origin() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		// The first tag is the moderator role in the mock
+		let moderator_tag = ModeratorTag::get();
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![109, 111, 100]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+
+		// Without the tag the origin is rejected
+		assert!(EnsureTagged::<Test, ConstU64<0>>::try_origin(RuntimeOrigin::signed(2)).is_err());
+		assert!(EnsureTagged::<Test, ConstU64<0>>::try_origin(RuntimeOrigin::root()).is_err());
+
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), moderator_tag, 2));
+		assert_eq!(EnsureTagged::<Test, ConstU64<0>>::try_origin(RuntimeOrigin::signed(2)).ok(), Some(2));
+
+		// The tag works as a role for the moderation calls
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(3), 1, bvec![]));
+		assert_noop!(TagModule::dismiss_report(RuntimeOrigin::signed(3), 1), DispatchError::BadOrigin);
+		assert_ok!(TagModule::dismiss_report(RuntimeOrigin::signed(2), 1));
+
+		// Removing the tag from the account revokes the role
+		assert_ok!(TagModule::untag_account(RuntimeOrigin::signed(1), moderator_tag, 2));
+		assert!(EnsureTagged::<Test, ConstU64<0>>::try_origin(RuntimeOrigin::signed(2)).is_err());
+
+		// So does starting to destroy the tag, before its references are gone
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), moderator_tag, 2));
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), moderator_tag));
+		assert!(AccountTags::<Test>::contains_key(2, moderator_tag));
+		assert!(!TagModule::has_tag(&2, moderator_tag));
+		assert!(EnsureTagged::<Test, ConstU64<0>>::try_origin(RuntimeOrigin::signed(2)).is_err());
+	});
+}
+
+#[test]
+fn create_tag_with_signature() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// The signer has no funds for fees, the relayer pays for them
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let name = bvec![65, 66, 67];
+		let deposit = TagDepositAmount::get();
+
+		// First tag, the signer pays the deposit
+		let payload = PreSignedTag { name: name.clone(), deadline: 5, nonce: 0, signer_pays_deposit: true };
+		let signature = TestSignature(1, TagModule::signed_message(&payload));
+		assert_ok!(TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload, 1, signature));
+
+		assert_eq!(TagMap::<Test>::get(0), Some((name.clone(), 1, deposit)));
+		assert_eq!(TagDepositors::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(&1), deposit);
+		assert_eq!(SignerNonces::<Test>::get(1), 1);
+		System::assert_has_event(Event::TagCreated { index: 0, who: 1, name: name.clone(), deposit, created_at: START }.into());
+		System::assert_last_event(Event::TagRelayed { index: 0, signer: 1, relayer: 2, deposit_payer: 1 }.into());
+
+		// Second tag, the relayer pays the deposit
+		let name = bvec![68, 69, 70];
+		let payload = PreSignedTag { name: name.clone(), deadline: 5, nonce: 1, signer_pays_deposit: false };
+		let signature = TestSignature(1, TagModule::signed_message(&payload));
+		assert_ok!(TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload, 1, signature));
+
+		assert_eq!(TagMap::<Test>::get(1), Some((name, 1, deposit)));
+		assert_eq!(TagDepositors::<Test>::get(1), Some(2));
+		assert_eq!(Balances::reserved_balance(&2), deposit);
+		System::assert_last_event(Event::TagRelayed { index: 1, signer: 1, relayer: 2, deposit_payer: 2 }.into());
+
+		// The signer owns the tag, but destroying it gives the deposit back to the relayer
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 1));
+		assert_eq!(TagDepositors::<Test>::get(1), None);
+		assert_eq!(Balances::reserved_balance(&1), deposit);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn create_tag_with_signature_errors() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let payload = PreSignedTag { name: bvec![65], deadline: 5, nonce: 0, signer_pays_deposit: true };
+
+		// Signed by someone else
+		let signature = TestSignature(3, TagModule::signed_message(&payload));
+		assert_noop!(
+			TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload.clone(), 1, signature),
+			Error::<Test>::WrongSignature
+		);
+
+		// Signed without the signing context and genesis hash, as it could come from another chain
+		let signature = TestSignature(1, payload.encode());
+		assert_noop!(
+			TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload.clone(), 1, signature),
+			Error::<Test>::WrongSignature
+		);
+
+		// Signed over other data
+		let other = PreSignedTag { nonce: 1, ..payload.clone() };
+		let signature = TestSignature(1, TagModule::signed_message(&other));
+		assert_noop!(
+			TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload.clone(), 1, signature),
+			Error::<Test>::WrongSignature
+		);
+
+		// Wrong nonce
+		let signature = TestSignature(1, TagModule::signed_message(&other));
+		assert_noop!(
+			TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), other, 1, signature),
+			Error::<Test>::WrongNonce
+		);
+
+		// The payload can't be replayed
+		let signature = TestSignature(1, TagModule::signed_message(&payload));
+		assert_ok!(TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload.clone(), 1, signature.clone()));
+		assert_noop!(
+			TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload, 1, signature),
+			Error::<Test>::WrongNonce
+		);
+
+		// Past the deadline
+		System::set_block_number(6);
+		let payload = PreSignedTag { name: bvec![65], deadline: 5, nonce: 1, signer_pays_deposit: true };
+		let signature = TestSignature(1, TagModule::signed_message(&payload));
+		assert_noop!(
+			TagModule::create_tag_with_signature(RuntimeOrigin::signed(2), payload, 1, signature),
+			Error::<Test>::DeadlineExpired
+		);
+	});
+}
+
+#[test]
+fn list_and_buy_tag() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let name = bvec![65, 66, 67];
+		let deposit = TagDepositAmount::get();
+		let tag_index = 0;
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), name.clone()));
+
+		// Only the owner can list the tag
+		assert_noop!(TagModule::list_tag(RuntimeOrigin::signed(2), tag_index, 10), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::buy_tag(RuntimeOrigin::signed(2), tag_index, 10), Error::<Test>::NotForSale);
+
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(1), tag_index, 10));
+		assert_eq!(Listings::<Test>::get(tag_index), Some(10));
+		System::assert_last_event(Event::TagListed { index: tag_index, seller: 1, price: 10, updated_at: START }.into());
+
+		// The buyer sets the maximum price it accepts
+		assert_noop!(TagModule::buy_tag(RuntimeOrigin::signed(2), tag_index, 9), Error::<Test>::PriceTooHigh);
+		assert_noop!(TagModule::buy_tag(RuntimeOrigin::signed(1), tag_index, 10), Error::<Test>::AlreadyOwner);
+
+		assert_ok!(TagModule::buy_tag(RuntimeOrigin::signed(2), tag_index, 10));
+		System::assert_last_event(Event::TagSold { index: tag_index, seller: 1, buyer: 2, price: 10, updated_at: START }.into());
+
+		// The seller got paid and its deposit back, the buyer owns the tag and holds the deposit
+		assert_eq!(TagMap::<Test>::get(tag_index), Some((name, 2, deposit)));
+		assert_eq!(Listings::<Test>::get(tag_index), None);
+		assert_eq!(Balances::free_balance(&1), 110);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&2), 90 - deposit);
+		assert_eq!(Balances::reserved_balance(&2), deposit);
+		assert_eq!(pallet_tag_guard::Known::<Test>::get(tag_index), Some(2));
+
+		// The new owner can destroy it
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), tag_index), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(2), tag_index));
+		assert_eq!(Balances::free_balance(&2), 90);
+	});
+}
+
+#[test]
+fn delist_and_frozen_tags() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		let tag_index = 0;
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(1), tag_index, 10));
+
+		// Only the owner can delist it
+		assert_noop!(TagModule::delist_tag(RuntimeOrigin::signed(2), tag_index), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::delist_tag(RuntimeOrigin::signed(1), tag_index));
+		System::assert_last_event(Event::TagDelisted { index: tag_index, updated_at: START }.into());
+		assert_noop!(TagModule::delist_tag(RuntimeOrigin::signed(1), tag_index), Error::<Test>::NotForSale);
+		assert_noop!(TagModule::buy_tag(RuntimeOrigin::signed(2), tag_index, 10), Error::<Test>::NotForSale);
+
+		// A reported tag is frozen, it can't be listed nor bought
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(1), tag_index, 10));
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(3), tag_index, bvec![]));
+		assert_noop!(TagModule::buy_tag(RuntimeOrigin::signed(2), tag_index, 10), Error::<Test>::UnderReview);
+		assert_noop!(TagModule::list_tag(RuntimeOrigin::signed(1), tag_index, 5), Error::<Test>::UnderReview);
+
+		// Removing the tag also removes the listing
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), tag_index));
+		assert_eq!(Listings::<Test>::get(tag_index), None);
+	});
+}
+
+#[test]
+fn tag_names_are_unique() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let name = bvec![65, 66, 67];
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), name.clone()));
+		assert_eq!(TagNames::<Test>::get(&name), Some(0));
+
+		assert_noop!(TagModule::create_tag(RuntimeOrigin::signed(2), name.clone()), Error::<Test>::NameTaken);
+
+		// The name is free again once the tag is destroyed
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(TagNames::<Test>::get(&name), None);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(2), name.clone()));
+		assert_eq!(TagNames::<Test>::get(&name), Some(1));
+	});
+}
+
+#[test]
+fn commit_and_reveal_tag() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let name = bvec![65, 66, 67];
+		let salt = [7u8; 32];
+		let hash = BlakeTwo256::hash_of(&(1u64, &name, &salt));
+		let commitment_deposit = CommitmentDeposit::get();
+		let deposit = TagDepositAmount::get();
+
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(1), hash));
+		assert_eq!(Commitments::<Test>::get(hash), Some((1, 1, commitment_deposit)));
+		assert_eq!(Balances::reserved_balance(&1), commitment_deposit);
+		System::assert_last_event(Event::TagCommitted { hash, who: 1 }.into());
+		assert_noop!(TagModule::commit_tag(RuntimeOrigin::signed(1), hash), Error::<Test>::AlreadyCommitted);
+
+		// Too early to reveal
+		assert_noop!(TagModule::reveal_tag(RuntimeOrigin::signed(1), name.clone(), salt), Error::<Test>::CommitmentTooNew);
+
+		// Another account can't reveal the commitment, the hash includes the committer
+		System::set_block_number(1 + MinCommitmentAge::get());
+		assert_noop!(TagModule::reveal_tag(RuntimeOrigin::signed(2), name.clone(), salt), Error::<Test>::NoCommitment);
+
+		assert_ok!(TagModule::reveal_tag(RuntimeOrigin::signed(1), name.clone(), salt));
+		assert_eq!(TagMap::<Test>::get(0), Some((name.clone(), 1, deposit)));
+		assert_eq!(Commitments::<Test>::get(hash), None);
+		assert_eq!(Balances::reserved_balance(&1), deposit);
+		System::assert_last_event(Event::TagRevealed { index: 0, hash }.into());
+	});
+}
+
+#[test]
+fn earliest_reveal_wins() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let name = bvec![65, 66, 67];
+		let hash_1 = BlakeTwo256::hash_of(&(1u64, &name, &[1u8; 32]));
+		let hash_2 = BlakeTwo256::hash_of(&(2u64, &name, &[2u8; 32]));
+
+		// Both accounts commit to the same name
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(1), hash_1));
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(2), hash_2));
+
+		System::set_block_number(1 + MinCommitmentAge::get());
+
+		// The first reveal gets the name, the second one fails and keeps its commitment
+		assert_ok!(TagModule::reveal_tag(RuntimeOrigin::signed(2), name.clone(), [2u8; 32]));
+		assert_noop!(TagModule::reveal_tag(RuntimeOrigin::signed(1), name.clone(), [1u8; 32]), Error::<Test>::NameTaken);
+		assert_eq!(TagNames::<Test>::get(&name), Some(0));
+
+		// The loser gets its deposit back by clearing the commitment
+		assert_ok!(TagModule::clear_commitment(RuntimeOrigin::signed(1), hash_1));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		System::assert_last_event(Event::CommitmentCleared { hash: hash_1, who: 1 }.into());
+	});
+}
+
+#[test]
+fn first_reveal_wins() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let name: BoundedVec<u8, TagNameLimit> = bvec![65, 66, 67];
+		let salt = [7u8; 32];
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(1), BlakeTwo256::hash_of(&(1u64, &name, &salt))));
+		System::set_block_number(1 + MinCommitmentAge::get());
+
+		// Having seen the reveal in the pool, account 2 commits to the name too, but its
+		// commitment is too new to be revealed before the one it copied
+		let other_salt = [8u8; 32];
+		let other_hash = BlakeTwo256::hash_of(&(2u64, &name, &other_salt));
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(2), other_hash));
+		assert_noop!(
+			TagModule::reveal_tag(RuntimeOrigin::signed(2), name.clone(), other_salt),
+			Error::<Test>::CommitmentTooNew
+		);
+
+		assert_ok!(TagModule::reveal_tag(RuntimeOrigin::signed(1), name.clone(), salt));
+		assert_eq!(TagMap::<Test>::get(0).map(|(_, owner, _)| owner), Some(1));
+
+		// Later reveals of the same name fail, and the commitment can be cleared
+		System::set_block_number(1 + 2 * MinCommitmentAge::get());
+		assert_noop!(
+			TagModule::reveal_tag(RuntimeOrigin::signed(2), name.clone(), other_salt),
+			Error::<Test>::NameTaken
+		);
+		assert_ok!(TagModule::clear_commitment(RuntimeOrigin::signed(2), other_hash));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn revealed_names_only_filter() {
+	let name: BoundedVec<u8, TagNameLimit> = bvec![65, 66, 67];
+	let call = RuntimeCall::TagModule(crate::Call::create_tag { name: name.clone() });
+	assert!(!RevealedNamesOnly::<Test>::contains(&call));
+	let call = RuntimeCall::TagModule(crate::Call::reveal_tag { name, salt: [7u8; 32] });
+	assert!(RevealedNamesOnly::<Test>::contains(&call));
+}
+
+#[test]
+fn expired_commitments() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let name = bvec![65, 66, 67];
+		let salt = [7u8; 32];
+		let hash = BlakeTwo256::hash_of(&(1u64, &name, &salt));
+
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(1), hash));
+
+		// Only the committer can clear a live commitment
+		assert_noop!(TagModule::clear_commitment(RuntimeOrigin::signed(2), hash), Error::<Test>::CommitmentNotExpired);
+
+		// Once expired it can't be revealed, but anyone can clear it
+		System::set_block_number(2 + MaxCommitmentAge::get());
+		assert_noop!(TagModule::reveal_tag(RuntimeOrigin::signed(1), name, salt), Error::<Test>::CommitmentExpired);
+		assert_ok!(TagModule::clear_commitment(RuntimeOrigin::signed(2), hash));
+		assert_eq!(Commitments::<Test>::get(hash), None);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_noop!(TagModule::clear_commitment(RuntimeOrigin::signed(1), hash), Error::<Test>::NoCommitment);
+	});
+}
+
+#[test]
+fn set_and_clear_translations() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+
+		// Only the owner can translate the tag
+		assert_noop!(
+			TagModule::set_tag_translation(RuntimeOrigin::signed(2), 0, bvec![112, 116], bvec![97]),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			TagModule::set_tag_translation(RuntimeOrigin::signed(1), 1, bvec![112, 116], bvec![97]),
+			Error::<Test>::InvalidTag
+		);
+
+		// The deposit is paid per byte of language code and name
+		assert_ok!(TagModule::set_tag_translation(RuntimeOrigin::signed(1), 0, bvec![112, 116], bvec![97, 98, 99]));
+		System::assert_last_event(Event::TagTranslationSet { index: 0, lang: bvec![112, 116], text: bvec![97, 98, 99], deposit: 5, updated_at: START }.into());
+		assert_eq!(Balances::reserved_balance(&1), 1 + 5);
+
+		// Replacing a translation returns the previous deposit
+		assert_ok!(TagModule::set_tag_translation(RuntimeOrigin::signed(1), 0, bvec![112, 116], bvec![97]));
+		assert_eq!(Balances::reserved_balance(&1), 1 + 3);
+		assert_eq!(TranslationCounts::<Test>::get(0), 1);
+
+		// The number of languages is bounded
+		assert_ok!(TagModule::set_tag_translation(RuntimeOrigin::signed(1), 0, bvec![101, 115], bvec![97]));
+		assert_noop!(
+			TagModule::set_tag_translation(RuntimeOrigin::signed(1), 0, bvec![102, 114], bvec![97]),
+			Error::<Test>::TooManyTranslations
+		);
+
+		// Names fall back to the canonical one
+		assert_eq!(TagModule::localized_name(0, &[112, 116]), Some(vec![97]));
+		assert_eq!(TagModule::localized_name(0, &[102, 114]), Some(vec![65, 66, 67]));
+		assert_eq!(TagModule::localized_name(0, &[0; 64]), Some(vec![65, 66, 67]));
+		assert_eq!(TagModule::localized_name(1, &[112, 116]), None);
+
+		assert_ok!(TagModule::clear_tag_translation(RuntimeOrigin::signed(1), 0, bvec![112, 116]));
+		System::assert_last_event(Event::TagTranslationCleared { index: 0, lang: bvec![112, 116], updated_at: START }.into());
+		assert_eq!(Balances::reserved_balance(&1), 1 + 3);
+		assert_eq!(TranslationCounts::<Test>::get(0), 1);
+		assert_noop!(
+			TagModule::clear_tag_translation(RuntimeOrigin::signed(1), 0, bvec![112, 116]),
+			Error::<Test>::NoTranslation
+		);
+	});
+}
+
+#[test]
+fn translations_removed_with_tag() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+		assert_ok!(TagModule::set_tag_translation(RuntimeOrigin::signed(1), 0, bvec![112, 116], bvec![97]));
+		assert_ok!(TagModule::set_tag_translation(RuntimeOrigin::signed(1), 0, bvec![101, 115], bvec![98]));
+
+		// Destroying the tag returns every deposit
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(TagTranslations::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(TranslationCounts::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn multi_block_destruction() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let name = bvec![65, 66, 67];
+		let tag_index = 0;
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), name.clone()));
+		for account in 2..7 {
+			assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), tag_index, account));
+		}
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(1), tag_index, 10));
+
+		// Nothing to do before the destruction starts
+		assert_noop!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), tag_index, 5), Error::<Test>::NotDestroying);
+		assert_noop!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(2), tag_index), Error::<Test>::NotDestroying);
+
+		// Only the owner can start it
+		assert_noop!(TagModule::start_destroy_tag(RuntimeOrigin::signed(2), tag_index), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), tag_index));
+		System::assert_last_event(Event::DestructionStarted { index: tag_index }.into());
+		assert_eq!(TagStatuses::<Test>::get(tag_index), TagStatus::Destroying);
+		assert_eq!(Listings::<Test>::get(tag_index), None);
+		assert_noop!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), tag_index), Error::<Test>::TagDestroying);
+
+		// The tag can't be applied or sold anymore
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), tag_index, 7), Error::<Test>::TagDestroying);
+		assert_noop!(TagModule::list_tag(RuntimeOrigin::signed(1), tag_index, 10), Error::<Test>::TagDestroying);
+
+		// References are removed in bounded steps, by anyone
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), tag_index, 10));
+		System::assert_last_event(Event::ReferencesDestroyed { index: tag_index, removed: 2, remaining: 3 }.into());
+		assert_noop!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(2), tag_index), Error::<Test>::TagInUse);
+
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), tag_index, 1));
+		System::assert_last_event(Event::ReferencesDestroyed { index: tag_index, removed: 1, remaining: 2 }.into());
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), tag_index, 2));
+		assert_eq!(TagReferences::<Test>::get(tag_index), 0);
+		assert_eq!(AccountsByTag::<Test>::iter_prefix(tag_index).count(), 0);
+		assert!((2..7).all(|account| !AccountTags::<Test>::contains_key(account, tag_index)));
+
+		// Finishing releases the deposit
+		assert_ok!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(2), tag_index));
+		System::assert_last_event(Event::TagDestroyed { index: tag_index, who: 1, name: name.clone() }.into());
+		assert_eq!(TagMap::<Test>::get(tag_index), None);
+		assert_eq!(TagNames::<Test>::get(&name), None);
+		assert_eq!(TagStatuses::<Test>::get(tag_index), TagStatus::Live);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn destruction_respects_lifecycle_and_reports() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+
+		// Other pallets can veto the destruction before it starts
+		pallet_tag_guard::Protected::<Test>::insert(0, ());
+		assert_noop!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0), pallet_tag_guard::Error::<Test>::Protected);
+		pallet_tag_guard::Protected::<Test>::remove(0);
+
+		// Reported tags can't be destroyed
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 0, bvec![]));
+		assert_noop!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0), Error::<Test>::UnderReview);
+		assert_ok!(TagModule::dismiss_report(RuntimeOrigin::root(), 0));
+
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0));
+
+		// Nor can tags being destroyed be reported, so no bond is left behind once they are gone
+		assert_noop!(TagModule::report_tag(RuntimeOrigin::signed(2), 0, bvec![]), Error::<Test>::TagDestroying);
+
+		assert_ok!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(pallet_tag_guard::Known::<Test>::get(0), None);
+		assert_eq!(Reports::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn cleanup_tasks() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 3));
+
+		// There is no work while the tag is live
+		assert_eq!(Task::<Test>::iter().count(), 0);
+		let purge = Task::<Test>::purge_tag_reference { tag_index: 0, account: 2 };
+		assert_noop!(
+			System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge.clone())),
+			frame_system::Error::<Test>::InvalidTask
+		);
+
+		// Once the tag is being destroyed, its references can be purged by anyone
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Task::<Test>::iter().count(), 2);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge.clone())));
+		System::assert_has_event(Event::AccountUntagged { index: 0, account: 2 }.into());
+		assert!(!AccountTags::<Test>::contains_key(2, 0));
+		assert!(!purge.is_valid());
+
+		assert_ok!(System::do_task(
+			RuntimeOrigin::none(),
+			RuntimeTask::TagModule(Task::<Test>::purge_tag_reference { tag_index: 0, account: 3 })
+		));
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+
+		// Then the destruction can be finished
+		let finish = Task::<Test>::finish_tag_destruction { tag_index: 0 };
+		assert_eq!(Task::<Test>::iter().collect::<Vec<_>>(), vec![finish.clone()]);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(finish)));
+		assert_eq!(TagMap::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Task::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn purge_references_to_removed_tags() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 3));
+
+		// A moderator removes the tag, leaving the reference behind
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 0, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 0));
+
+		let purge = Task::<Test>::purge_tag_reference { tag_index: 0, account: 3 };
+		assert_eq!(Task::<Test>::iter().collect::<Vec<_>>(), vec![purge.clone()]);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge)));
+		assert!(!AccountTags::<Test>::contains_key(3, 0));
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn clear_expired_commitment_task() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let hash = BlakeTwo256::hash_of(&(1u64, [65u8, 66, 67], [7u8; 32]));
+		assert_ok!(TagModule::commit_tag(RuntimeOrigin::signed(1), hash));
+
+		// Live commitments can't be cleared by a task
+		let task = Task::<Test>::clear_expired_commitment { hash };
+		assert!(!task.is_valid());
+
+		System::set_block_number(2 + MaxCommitmentAge::get());
+		assert!(task.is_valid());
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(task)));
+		System::assert_last_event(Event::CommitmentCleared { hash, who: 1 }.into());
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn tag_and_untag_item() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(2);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+
+		// Only the owner of the tag can apply it, and only to existing items
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(2), 0, collection, 0, 0), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0), Error::<Test>::UnknownItem);
+
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+		System::assert_last_event(Event::ItemTagged { index: 0, collection, item: 0, who: 1, deposit: 1, tagged_at: START }.into());
+		assert!(ItemsByTag::<Test>::contains_key(0, (collection, 0)));
+		assert!(TagsByItem::<Test>::contains_key((collection, 0), 0));
+		assert_eq!(ItemTagCounts::<Test>::get((collection, 0)), 1);
+		assert_eq!(TagReferences::<Test>::get(0), 1);
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0), Error::<Test>::AlreadyTagged);
+
+		// The tag can't be destroyed while an item carries it
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0), Error::<Test>::TagInUse);
+
+		// Items carry a bounded number of tags
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![68]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![69]));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 1, collection, 0, 0));
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(1), 2, collection, 0, 0), Error::<Test>::TooManyTags);
+
+		// Other accounts can't remove the tag, the owner of the item can
+		assert_noop!(TagModule::untag_item(RuntimeOrigin::signed(3), 0, collection, 0), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(2), 0, collection, 0));
+		System::assert_last_event(Event::ItemUntagged { index: 0, collection, item: 0 }.into());
+		assert!(!ItemsByTag::<Test>::contains_key(0, (collection, 0)));
+		assert!(!TagsByItem::<Test>::contains_key((collection, 0), 0));
+		assert_eq!(ItemTagCounts::<Test>::get((collection, 0)), 1);
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+		assert_noop!(TagModule::untag_item(RuntimeOrigin::signed(2), 0, collection, 0), Error::<Test>::NotTagged);
+
+		// And so can the owner of the tag
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(1), 1, collection, 0));
+		assert_eq!(ItemTagCounts::<Test>::get((collection, 0)), 0);
+	});
+}
+
+#[test]
+fn paginated_indexes() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(1);
+		for item in 1..5 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), collection, item, 1, None));
+		}
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		for item in 0..5 {
+			assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, item, 0));
+		}
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 1, collection, 3, 0));
+
+		// Walk the items of the tag two at a time
+		let mut items = Vec::new();
+		let mut cursor = None;
+		loop {
+			let page = TagModule::items_by_tag(0, cursor, 2);
+			assert!(page.len() <= 2);
+			if page.is_empty() {
+				break;
+			}
+			cursor = page.last().copied();
+			items.extend(page);
+		}
+		items.sort();
+		assert_eq!(items, (0..5).map(|item| (collection, item)).collect::<Vec<_>>());
+
+		// Walk the tags of an item one at a time
+		let first = TagModule::tags_by_item((collection, 3), None, 1);
+		let second = TagModule::tags_by_item((collection, 3), first.last().copied(), 1);
+		let mut tags = [first, second].concat();
+		tags.sort();
+		assert_eq!(tags, vec![0, 1]);
+		assert_eq!(TagModule::tags_by_item((collection, 3), None, 5).len(), 2);
+		assert_eq!(TagModule::tags_by_item((collection, 0), None, 5), vec![0]);
+		assert_eq!(TagModule::items_by_tag(2, None, 5), vec![]);
+	});
+}
+
+#[test]
+fn destroy_tag_on_items() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(1);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), collection, 1, 1, None));
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0));
+
+		// Accounts and items are removed within the same limit
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0), Error::<Test>::TagDestroying);
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), 0, 5));
+		System::assert_last_event(Event::ReferencesDestroyed { index: 0, removed: 2, remaining: 1 }.into());
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), 0, 5));
+		System::assert_last_event(Event::ReferencesDestroyed { index: 0, removed: 1, remaining: 0 }.into());
+
+		assert_eq!(TagModule::items_by_tag(0, None, 5), vec![]);
+		assert_eq!(TagModule::tags_by_item((collection, 0), None, 5), vec![]);
+		assert_eq!(ItemTagCounts::<Test>::get((collection, 1)), 0);
+		assert_ok!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(2), 0));
+	});
+}
+
+#[test]
+fn purge_tags_of_burned_items() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+
+		// Nothing to purge while the item exists
+		let purge = Task::<Test>::purge_item_tag { tag_index: 0, item: (collection, 0) };
+		assert!(!purge.is_valid());
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(2), collection, 0));
+		assert_eq!(Task::<Test>::iter().collect::<Vec<_>>(), vec![purge.clone()]);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge)));
+		assert_eq!(TagModule::tags_by_item((collection, 0), None, 5), vec![]);
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+
+		// The tag can be destroyed right away
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+	});
+}
+
+#[test]
+fn open_tagging() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		let collection = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+
+		// Only the owner of the tag can apply it by default
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 0), Error::<Test>::NotAllowed);
+
+		// Anyone can when tagging is open, and there's nothing to propose
+		TaggingMode::set(&TaggingPolicy::Open);
+		assert_noop!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 0, collection, 0, 0), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 0));
+		assert!(TagsByItem::<Test>::contains_key((collection, 0), 0));
+	});
+}
+
+#[test]
+fn item_owner_approval() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		TaggingMode::set(&TaggingPolicy::ItemOwnerApproval);
+		let collection = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+
+		// Not even the owner of the tag can apply it without approval
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0), Error::<Test>::ApprovalRequired);
+		assert_noop!(TagModule::accept_item_tag(RuntimeOrigin::signed(2), 0, collection, 0), Error::<Test>::NoProposal);
+
+		assert_ok!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 0, collection, 0, 0));
+		System::assert_last_event(Event::TagProposed { index: 0, collection, item: 0, who: 3, deposit: 3 }.into());
+		assert_eq!(TagProposals::<Test>::get((collection, 0), 0), Some((3, 3, 0)));
+		assert_eq!(Balances::reserved_balance(&3), 3);
+		assert_noop!(TagModule::propose_item_tag(RuntimeOrigin::signed(1), 0, collection, 0, 0), Error::<Test>::AlreadyProposed);
+
+		// Only the owner of the item answers, only the proposer withdraws
+		assert_noop!(TagModule::accept_item_tag(RuntimeOrigin::signed(1), 0, collection, 0), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::withdraw_item_tag(RuntimeOrigin::signed(2), 0, collection, 0), Error::<Test>::NotAllowed);
+
+		// Accepting applies the tag and returns the deposit, the proposer pays the tagging deposit
+		assert_ok!(TagModule::accept_item_tag(RuntimeOrigin::signed(2), 0, collection, 0));
+		System::assert_has_event(Event::ItemTagged { index: 0, collection, item: 0, who: 3, deposit: 1, tagged_at: START }.into());
+		System::assert_last_event(Event::ProposalAccepted { index: 0, collection, item: 0 }.into());
+		assert!(TagsByItem::<Test>::contains_key((collection, 0), 0));
+		assert!(!TagProposals::<Test>::contains_key((collection, 0), 0));
+		assert_eq!(ItemsByTag::<Test>::get(0, (collection, 0)), Some((3, 1)));
+		assert_eq!(Balances::reserved_balance(&3), 1);
+		assert_noop!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 0, collection, 0, 0), Error::<Test>::AlreadyTagged);
+
+		// Rejecting slashes the deposit
+		assert_ok!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 1, collection, 0, 0));
+		assert_ok!(TagModule::reject_item_tag(RuntimeOrigin::signed(2), 1, collection, 0));
+		System::assert_last_event(Event::ProposalRejected { index: 1, collection, item: 0, slashed: 3 }.into());
+		assert_eq!(Balances::free_balance(&3), 96);
+		assert_eq!(Balances::free_balance(&TREASURY), 3);
+
+		// Withdrawing returns it
+		assert_ok!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 1, collection, 0, 0));
+		assert_ok!(TagModule::withdraw_item_tag(RuntimeOrigin::signed(3), 1, collection, 0));
+		System::assert_last_event(Event::ProposalWithdrawn { index: 1, collection, item: 0 }.into());
+		assert_eq!(Balances::free_balance(&3), 96);
+		assert_eq!(Balances::reserved_balance(&3), 1);
+
+		// The owner of the item doesn't need approval
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(2), 1, collection, 0, 0));
+		assert!(TagsByItem::<Test>::contains_key((collection, 0), 1));
+	});
+}
+
+#[test]
+fn tagging_deposits() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		TaggingMode::set(&TaggingPolicy::Open);
+		let collection = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+
+		// The deposit is reserved from the tagger
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 0));
+		assert_eq!(ItemsByTag::<Test>::get(0, (collection, 0)), Some((3, 1)));
+		assert_eq!(Balances::reserved_balance(&3), 1);
+
+		// The tagger gets it back when it removes the tag, or when the owner of the tag does
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(3), 0, collection, 0));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 0));
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(1), 0, collection, 0));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+
+		// And when the owner of the item does, unless the runtime slashes it
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 0));
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(2), 0, collection, 0));
+		assert_eq!(Balances::free_balance(&3), 100);
+
+		SlashRemovedTaggings::set(&true);
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 0));
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(2), 0, collection, 0));
+		assert_eq!(Balances::free_balance(&3), 99);
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		assert_eq!(Balances::free_balance(&TREASURY), 1);
+
+		// Removing its own tags doesn't slash the owner of the item
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(2), 0, collection, 0, 0));
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(2), 0, collection, 0));
+		assert_eq!(Balances::free_balance(&2), 100);
+
+		// Destroying the tag returns the deposits
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 1, collection, 0, 0));
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 1));
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(1), 1, 5));
+		assert_eq!(Balances::reserved_balance(&3), 0);
+	});
+}
+
+#[test]
+fn usage_fees() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		TaggingMode::set(&TaggingPolicy::Open);
+		let collection = create_item(2);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(2), collection, 1, 2, None));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+
+		// Only the owner of the tag sets the fee, up to the cap
+		assert_noop!(TagModule::set_usage_fee(RuntimeOrigin::signed(3), 0, Some(5)), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::set_usage_fee(RuntimeOrigin::signed(1), 0, Some(11)), Error::<Test>::FeeTooHigh);
+		assert_ok!(TagModule::set_usage_fee(RuntimeOrigin::signed(1), 0, Some(5)));
+		System::assert_last_event(Event::UsageFeeSet { index: 0, fee: Some(5), updated_at: START }.into());
+		assert_eq!(UsageFees::<Test>::get(0), Some(5));
+
+		// Quotes include the deposit, and the fee for everyone but the owner
+		assert_eq!(TagModule::tagging_cost(0, &3), Some(6));
+		assert_eq!(TagModule::tagging_cost(0, &1), Some(1));
+		assert_eq!(TagModule::tagging_cost(1, &3), None);
+
+		// The tagger caps the fee it pays
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 4), Error::<Test>::FeeAboveLimit);
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, collection, 0, 5));
+		System::assert_has_event(Event::FeePaid { index: 0, who: 3, owner: 1, fee: 5 }.into());
+		assert_eq!(Balances::free_balance(&3), 94);
+		assert_eq!(Balances::free_balance(&1), 104);
+
+		// The owner doesn't pay its own fee
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0));
+		assert_eq!(Balances::free_balance(&1), 103);
+
+		// A proposal keeps the cap of the proposer until it's accepted
+		TaggingMode::set(&TaggingPolicy::ItemOwnerApproval);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(2), collection, 2, 2, None));
+		assert_ok!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 0, collection, 2, 5));
+		assert_eq!(TagProposals::<Test>::get((collection, 2), 0), Some((3, 3, 5)));
+		assert_ok!(TagModule::set_usage_fee(RuntimeOrigin::signed(1), 0, Some(6)));
+		assert_noop!(TagModule::accept_item_tag(RuntimeOrigin::signed(2), 0, collection, 2), Error::<Test>::FeeAboveLimit);
+		assert_ok!(TagModule::set_usage_fee(RuntimeOrigin::signed(1), 0, Some(5)));
+		assert_ok!(TagModule::accept_item_tag(RuntimeOrigin::signed(2), 0, collection, 2));
+		assert_eq!(Balances::free_balance(&1), 108);
+
+		assert_ok!(TagModule::set_usage_fee(RuntimeOrigin::signed(1), 0, None));
+		System::assert_last_event(Event::UsageFeeSet { index: 0, fee: None, updated_at: START }.into());
+		assert_eq!(TagModule::tagging_cost(0, &3), Some(1));
+
+		// Tags being destroyed can't be applied
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(TagModule::tagging_cost(0, &3), None);
+	});
+}
+
+#[test]
+fn tag_and_untag_collection() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(2);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+
+		// Only the owner of the tag can apply it, and only to existing collections
+		assert_noop!(TagModule::tag_collection(RuntimeOrigin::signed(2), 0, collection), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, collection + 1), Error::<Test>::UnknownCollection);
+
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, collection));
+		System::assert_last_event(Event::CollectionTagged { index: 0, collection, tagged_at: START }.into());
+		assert!(CollectionsByTag::<Test>::contains_key(0, collection));
+		assert!(TagsByCollection::<Test>::contains_key(collection, 0));
+		assert_eq!(CollectionTagCounts::<Test>::get(collection), 1);
+		assert_eq!(TagReferences::<Test>::get(0), 1);
+		assert_noop!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, collection), Error::<Test>::AlreadyTagged);
+
+		// The tag can't be destroyed while a collection carries it
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0), Error::<Test>::TagInUse);
+
+		// Collections carry a bounded number of tags
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![68]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![69]));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 1, collection));
+		assert_noop!(TagModule::tag_collection(RuntimeOrigin::signed(1), 2, collection), Error::<Test>::TooManyTags);
+
+		// Other accounts can't remove the tag, the owner of the collection can
+		assert_noop!(TagModule::untag_collection(RuntimeOrigin::signed(3), 0, collection), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::untag_collection(RuntimeOrigin::signed(2), 0, collection));
+		System::assert_last_event(Event::CollectionUntagged { index: 0, collection }.into());
+		assert!(!CollectionsByTag::<Test>::contains_key(0, collection));
+		assert!(!TagsByCollection::<Test>::contains_key(collection, 0));
+		assert_eq!(CollectionTagCounts::<Test>::get(collection), 1);
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+		assert_noop!(TagModule::untag_collection(RuntimeOrigin::signed(2), 0, collection), Error::<Test>::NotTagged);
+
+		// And so can the owner of the tag
+		assert_ok!(TagModule::untag_collection(RuntimeOrigin::signed(1), 1, collection));
+		assert_eq!(CollectionTagCounts::<Test>::get(collection), 0);
+	});
+}
+
+#[test]
+fn effective_tags() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let collection = create_item(1);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), collection, 1, 1, None));
+		for name in 65..69 {
+			assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![name]));
+		}
+
+		// Items inherit the tags of their collection, without duplicates
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 3, collection));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 1, collection));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 1, collection, 0, 0));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+		assert_eq!(TagModule::effective_tags((collection, 0)), vec![0, 1, 3]);
+		assert_eq!(TagModule::effective_tags((collection, 1)), vec![1, 3]);
+
+		// Only the own tags are listed by item
+		assert_eq!(TagModule::tags_by_item((collection, 1), None, 5), vec![]);
+
+		// Tags removed by a moderator aren't effective anymore
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 3, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 3));
+		assert_eq!(TagModule::effective_tags((collection, 1)), vec![1]);
+	});
+}
+
+#[test]
+fn destroy_tag_on_collections() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let first = create_item(1);
+		let second = create_item(1);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, first));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, second));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, first, 0, 0));
+
+		// Collections can't be tagged while the tag is being destroyed
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_ok!(TagModule::untag_collection(RuntimeOrigin::signed(1), 0, second));
+		assert_noop!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, second), Error::<Test>::TagDestroying);
+
+		// Collections are removed before items, within the same limit
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(2), 0, 5));
+		System::assert_last_event(Event::ReferencesDestroyed { index: 0, removed: 2, remaining: 0 }.into());
+		assert_eq!(CollectionTagCounts::<Test>::get(first), 0);
+		assert_eq!(TagModule::effective_tags((first, 0)), vec![]);
+		assert_ok!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(2), 0));
+	});
+}
+
+#[test]
+fn purge_tags_of_destroyed_collections() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, collection));
+
+		// Nothing to purge while the collection exists
+		let purge = Task::<Test>::purge_collection_tag { tag_index: 0, collection };
+		assert!(!purge.is_valid());
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(2), collection, 0));
+		let witness = <Nfts as Destroy<u64>>::get_destroy_witness(&collection).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(2), collection, witness));
+		assert_eq!(Task::<Test>::iter().collect::<Vec<_>>(), vec![purge.clone()]);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge)));
+		assert!(!TagsByCollection::<Test>::contains_key(collection, 0));
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+
+		// The tag can be destroyed right away
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+	});
+}
+
+/// The value of the attribute mirroring a tag on an item.
+fn mirrored_tag(collection: u32, item: u32, tag_index: u64) -> Option<Vec<u8>> {
+	let key = TagModule::attribute_key(tag_index).try_into().unwrap();
+	pallet_nfts::Attribute::<Test>::get((collection, Some(item), pallet_nfts::AttributeNamespace::Pallet, key))
+		.map(|(value, _deposit)| value.into_inner())
+}
+
+#[test]
+fn attribute_keys() {
+	assert_eq!(TagModule::attribute_key(0), b"tag:0".to_vec());
+	assert_eq!(TagModule::attribute_key(1234), b"tag:1234".to_vec());
+	assert_eq!(TagModule::attribute_key(u64::MAX), b"tag:18446744073709551615".to_vec());
+}
+
+#[test]
+fn mirror_tags_into_attributes() {
+	new_test_ext().execute_with(|| {
+		MirrorToAttributes::set(&true);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![106, 97, 122, 122]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![114, 111, 99, 107]));
+
+		// Tagging writes the name of the tag under its key
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 1, collection, 0, 0));
+		assert_eq!(mirrored_tag(collection, 0, 0), Some(b"jazz".to_vec()));
+		assert_eq!(mirrored_tag(collection, 0, 1), Some(b"rock".to_vec()));
+
+		// Untagging removes it
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(2), 0, collection, 0));
+		assert_eq!(mirrored_tag(collection, 0, 0), None);
+		assert_eq!(mirrored_tag(collection, 0, 1), Some(b"rock".to_vec()));
+
+		// And so does destroying the tag
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 1));
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(1), 1, 1));
+		assert_eq!(mirrored_tag(collection, 0, 1), None);
+	});
+}
+
+#[test]
+fn backfill_attributes() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(1);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), collection, 1, 1, None));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![106, 97, 122, 122]));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0));
+		assert_eq!(mirrored_tag(collection, 0, 0), None);
+
+		assert_noop!(TagModule::backfill_attributes(RuntimeOrigin::root(), 1), Error::<Test>::MirroringDisabled);
+
+		MirrorToAttributes::set(&true);
+		assert_noop!(TagModule::backfill_attributes(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+
+		// Existing tags are written in steps, resuming where the last call stopped
+		assert_ok!(TagModule::backfill_attributes(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AttributesBackfilled { count: 1, finished: false }.into());
+		assert!(AttributeBackfill::<Test>::get().is_some());
+
+		// An empty step is rejected rather than restarting the backfill
+		let cursor = AttributeBackfill::<Test>::get();
+		assert_noop!(TagModule::backfill_attributes(RuntimeOrigin::root(), 0), Error::<Test>::ZeroLimit);
+		assert_eq!(AttributeBackfill::<Test>::get(), cursor);
+
+		assert_ok!(TagModule::backfill_attributes(RuntimeOrigin::root(), 5));
+		System::assert_last_event(Event::AttributesBackfilled { count: 1, finished: true }.into());
+		assert_eq!(AttributeBackfill::<Test>::get(), None);
+
+		assert_eq!(mirrored_tag(collection, 0, 0), Some(b"jazz".to_vec()));
+		assert_eq!(mirrored_tag(collection, 1, 0), Some(b"jazz".to_vec()));
+	});
+}
+
+#[test]
+fn merge_tags() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		let collection = create_item(1);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), collection, 1, 1, None));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![110, 121, 99]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![110, 101, 119]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(3), bvec![121, 111, 114, 107]));
+
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 5));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, collection));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 0, 0));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0));
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), 1, collection, 1, 0));
+		assert_eq!(Balances::reserved_balance(&1), 5);
+
+		// Only the owner of both tags can merge them
+		assert_noop!(TagModule::merge_tags(RuntimeOrigin::signed(3), 0, 1, 5), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 2, 5), Error::<Test>::NotAllowed);
+		assert_noop!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 0, 5), Error::<Test>::SameTag);
+		assert_noop!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 9, 5), Error::<Test>::InvalidTag);
+
+		// The references are moved in bounded steps
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 5));
+		System::assert_has_event(Event::MergeStarted { from: 0, into: 1 }.into());
+		System::assert_last_event(Event::ReferencesMoved { from: 0, into: 1, moved: 2, remaining: 2 }.into());
+		assert_eq!(TagStatuses::<Test>::get(0), TagStatus::Merging { into: 1 });
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(1), 0, collection, 1, 0), Error::<Test>::TagDestroying);
+		assert_noop!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 2, 5), Error::<Test>::WrongMergeTarget);
+
+		// The target can't go away in the middle of the merge
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 1), Error::<Test>::TagInUse);
+
+		// Anyone can resume the merge, which removes the tag once it's done
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(3), 0, 1, 5));
+		System::assert_has_event(Event::ReferencesMoved { from: 0, into: 1, moved: 2, remaining: 0 }.into());
+		System::assert_last_event(Event::TagsMerged { from: 0, into: 1, name: bvec![110, 121, 99] }.into());
+		assert_eq!(TagMap::<Test>::get(0), None);
+		assert_eq!(TagStatuses::<Test>::get(0), TagStatus::Live);
+		assert_eq!(TagAliases::<Test>::get(0), Some(1));
+		assert!(!pallet_tag_guard::Known::<Test>::contains_key(0));
+
+		// Every target carries the other tag once, and the duplicated item got its deposit back
+		assert!(AccountTags::<Test>::contains_key(5, 1));
+		assert!(TagsByCollection::<Test>::contains_key(collection, 1));
+		assert_eq!(TagModule::tags_by_item((collection, 0), None, 5), vec![1]);
+		assert_eq!(TagModule::tags_by_item((collection, 1), None, 5), vec![1]);
+		assert_eq!(ItemTagCounts::<Test>::get((collection, 1)), 1);
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+		assert_eq!(TagReferences::<Test>::get(1), 4);
+		assert_eq!(Balances::reserved_balance(&1), 3);
+
+		// The old id and name resolve to the other tag, and the name stays taken
+		assert_eq!(TagModule::resolve_tag(0), Some(1));
+		assert_eq!(TagModule::tag_by_name(b"nyc"), Some(1));
+		assert_noop!(TagModule::create_tag(RuntimeOrigin::signed(3), bvec![110, 121, 99]), Error::<Test>::NameTaken);
+
+		// The force origin merges tags of any owner, and aliases are followed
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::root(), 1, 2, 2));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::root(), 1, 2, 2));
+		assert_eq!(TagReferences::<Test>::get(2), 4);
+		assert_eq!(TagModule::resolve_tag(0), Some(2));
+		assert_eq!(TagModule::tag_by_name(b"nyc"), Some(2));
+		assert_eq!(TagModule::tag_by_name(b"new"), Some(2));
+		assert_eq!(TagModule::resolve_tag(9), None);
+	});
+}
+
+#[test]
+fn merge_interrupted_by_moderation() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		for account in 5..8 {
+			assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, account));
+		}
+
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 2));
+		assert_eq!(TagReferences::<Test>::get(1), 3);
+
+		// The merged tag is removed by a moderator, so the other tag is no longer held
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 0, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 0));
+		assert_eq!(TagReferences::<Test>::get(1), 2);
+		assert_eq!(TagStatuses::<Test>::get(0), TagStatus::Live);
+	});
+}
+
+#[test]
+fn merge_into_removed_tag_is_aborted() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		for account in 5..8 {
+			assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, account));
+		}
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 1));
+
+		// The target is removed by a moderator, so resuming the merge gives it up
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 1, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 1));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(2), 0, 1, 10));
+		System::assert_last_event(Event::MergeAborted { from: 0, into: 1 }.into());
+		assert_eq!(TagStatuses::<Test>::get(0), TagStatus::Live);
+		assert_eq!(TagReferences::<Test>::get(0), 2);
+		assert_eq!(TagAliases::<Test>::get(0), None);
+		assert!(TagMap::<Test>::contains_key(0));
+
+		// Same when the target starts being destroyed
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![67]));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 2, 0));
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 2));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(2), 0, 2, 10));
+		System::assert_last_event(Event::MergeAborted { from: 0, into: 2 }.into());
+		assert_eq!(TagStatuses::<Test>::get(0), TagStatus::Live);
+		assert_eq!(TagReferences::<Test>::get(2), 0);
+		assert_ok!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(1), 2));
+	});
+}
+
+#[test]
+fn merge_refunds_reports_on_the_merged_tag() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 5));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 0));
+
+		// A report made while the merge runs is dropped with the merged tag
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 0, bvec![]));
+		assert!(Balances::reserved_balance(&2) > 0);
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 10));
+		assert_eq!(TagAliases::<Test>::get(0), Some(1));
+		assert_eq!(Reports::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn merging_tag_cant_be_destroyed() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 5));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 0));
+
+		// Even without references left, only the merge removes the tag
+		assert_ok!(TagModule::untag_account(RuntimeOrigin::signed(1), 0, 5));
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0), Error::<Test>::TagDestroying);
+		assert_eq!(TagReferences::<Test>::get(1), 1);
+
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 0));
+		assert_eq!(TagAliases::<Test>::get(0), Some(1));
+		assert_eq!(TagReferences::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn merged_names_are_freed_with_the_target() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 0, 1, 5));
+		assert_noop!(TagModule::create_tag(RuntimeOrigin::signed(3), bvec![65]), Error::<Test>::NameTaken);
+
+		// Once the target is destroyed, both names can be used again
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 1));
+		assert_eq!(TagModule::tag_by_name(b"A"), None);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(3), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(3), bvec![66]));
+		assert_eq!(TagModule::tag_by_name(b"A"), Some(2));
+		assert_eq!(TagModule::tag_by_name(b"B"), Some(3));
+	});
+}
+
+#[test]
+fn tag_activity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1000);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let created = BlockTime { block: 1, moment: 1000 };
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		let info = TagModule::tag_info(0).unwrap();
+		assert_eq!(info.name, vec![65]);
+		assert_eq!(info.owner, 1);
+		assert_eq!(info.status, TagStatus::Live);
+		assert_eq!(info.activity.created_at, created);
+		assert_eq!(info.activity.updated_at, created);
+		assert_eq!(info.activity.last_tagged_at, None);
+
+		// Applying the tag doesn't count as a change to it
+		System::set_block_number(3);
+		Timestamp::set_timestamp(3000);
+		let tagged = BlockTime { block: 3, moment: 3000 };
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::AccountTagged { index: 0, account: 2, tagged_at: tagged }.into());
+		let activity = TagActivities::<Test>::get(0).unwrap();
+		assert_eq!(activity.updated_at, created);
+		assert_eq!(activity.last_tagged_at, Some(tagged));
+
+		// Listing it does
+		System::set_block_number(5);
+		Timestamp::set_timestamp(5000);
+		let listed = BlockTime { block: 5, moment: 5000 };
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(1), 0, 10));
+		System::assert_last_event(Event::TagListed { index: 0, seller: 1, price: 10, updated_at: listed }.into());
+		let activity = TagActivities::<Test>::get(0).unwrap();
+		assert_eq!(activity.created_at, created);
+		assert_eq!(activity.updated_at, listed);
+		assert_eq!(activity.last_tagged_at, Some(tagged));
+
+		// The record goes away with the tag
+		assert_ok!(TagModule::untag_account(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(TagActivities::<Test>::get(0), None);
+		assert_eq!(TagModule::tag_info(0), None);
+	});
+}
+
+#[test]
+fn item_queries() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+
+		let collection = create_item(1);
+		for item in 1..5 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(1), collection, item, 1, None));
+		}
+
+		// Jazz is tag 0, vinyl tag 1 and used tag 2
+		for name in [bvec![65], bvec![66], bvec![67]] {
+			assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), name));
+		}
+		for (tag, item) in [(0, 0), (1, 0), (0, 1), (2, 1), (1, 2), (2, 2), (0, 3), (0, 4), (1, 4)] {
+			assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(1), tag, collection, item, 0));
+		}
+		assert_eq!(TaggedItemCounts::<Test>::get(0), 4);
+		assert_eq!(TaggedItemCounts::<Test>::get(1), 3);
+
+		// Follows the cursors through pages of a single item, returning the sorted items
+		let all_pages = |query: &dyn Fn(Option<(u32, u32)>) -> Option<(Vec<(u32, u32)>, Option<(u32, u32)>)>| {
+			let mut found = Vec::new();
+			let mut cursor = None;
+			loop {
+				let (items, next) = query(cursor).unwrap();
+				assert!(items.len() <= 1);
+				found.extend(items);
+				if next.is_none() {
+					break;
+				}
+				cursor = next;
+			}
+			found.sort();
+			found.into_iter().map(|(_, item)| item).collect::<Vec<_>>()
+		};
+
+		// Jazz and vinyl but not used
+		assert_eq!(all_pages(&|cursor| TagModule::items_with_all_tags(&[0, 1], &[2], cursor, 1)), vec![0, 4]);
+		// Jazz but not used
+		assert_eq!(all_pages(&|cursor| TagModule::items_with_all_tags(&[0], &[2], cursor, 1)), vec![0, 3, 4]);
+		// Vinyl or used, each item once
+		assert_eq!(all_pages(&|cursor| TagModule::items_with_any_tag(&[1, 2], &[], cursor, 1)), vec![0, 1, 2, 4]);
+		// Vinyl or used but not jazz
+		assert_eq!(all_pages(&|cursor| TagModule::items_with_any_tag(&[1, 2], &[0], cursor, 1)), vec![2]);
+
+		// A single page scans the whole tag when the limit allows
+		let (items, cursor) = TagModule::items_with_all_tags(&[0, 1], &[], None, 10).unwrap();
+		assert_eq!(items.len(), 2);
+		assert_eq!(cursor, None);
+
+		// Something has to be included, and the lists are bounded
+		assert_eq!(TagModule::items_with_all_tags(&[], &[0], None, 10), None);
+		assert_eq!(TagModule::items_with_any_tag(&[0, 1, 2, 3], &[], None, 10), None);
+		assert_eq!(TagModule::items_with_all_tags(&[0], &[1, 2, 3, 4], None, 10), None);
+
+		// Removing a tag updates the count of its items
+		assert_ok!(TagModule::untag_item(RuntimeOrigin::signed(1), 0, collection, 3));
+		assert_eq!(TaggedItemCounts::<Test>::get(0), 3);
+		assert_eq!(all_pages(&|cursor| TagModule::items_with_all_tags(&[0], &[2], cursor, 1)), vec![0, 4]);
+	});
+}
+
+#[test]
+fn tombstones() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		for name in [bvec![65], bvec![66], bvec![67], bvec![68]] {
+			assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), name));
+		}
+		assert_eq!(TagModule::tombstone(0), None);
+
+		// Destroyed by its owner
+		System::set_block_number(2);
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			TagModule::tombstone(0),
+			Some(Tombstone { destroyed_by: Some(1), destroyed_at: 2, successor: None })
+		);
+
+		// Removed by moderation
+		System::set_block_number(3);
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 1, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 1));
+		assert_eq!(
+			TagModule::tombstone(1),
+			Some(Tombstone { destroyed_by: None, destroyed_at: 3, successor: None })
+		);
+
+		// Merged into another tag
+		System::set_block_number(4);
+		assert_ok!(TagModule::merge_tags(RuntimeOrigin::signed(1), 2, 3, 2));
+		assert_eq!(
+			TagModule::tombstone(2),
+			Some(Tombstone { destroyed_by: Some(1), destroyed_at: 4, successor: Some(3) })
+		);
+
+		// Destroyed ids are told apart from ones that never existed
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 2), Error::<Test>::TagDestroyedAlready);
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 1), Error::<Test>::TagDestroyedAlready);
+		assert_noop!(TagModule::list_tag(RuntimeOrigin::signed(1), 2, 10), Error::<Test>::TagDestroyedAlready);
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), 9, 2), Error::<Test>::InvalidTag);
+		assert_eq!(TagModule::tombstone(3), None);
+		assert_eq!(TagModule::tombstone(9), None);
+	});
+}
+
+#[test]
+fn tag_proofs() {
+	use pallet_tags_proof::{tag_map_key, verify_tag_proof, ProofError, TagRecord, PALLET_NAME};
+
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65, 66, 67]));
+	});
+	ext.commit_all().unwrap();
+
+	// The verifier computes the same keys as the pallet
+	assert_eq!(tag_map_key(PALLET_NAME, 0), TagMap::<Test>::hashed_key_for(0));
+
+	let header = Header::<u64, BlakeTwo256> {
+		parent_hash: Default::default(),
+		number: 1,
+		state_root: *ext.as_backend().root(),
+		extrinsics_root: Default::default(),
+		digest: Default::default(),
+	};
+	let prove = |tag| -> Vec<Vec<u8>> {
+		sp_state_machine::prove_read(ext.as_backend(), [tag_map_key(PALLET_NAME, tag)])
+			.unwrap()
+			.into_iter_nodes()
+			.collect()
+	};
+
+	// The proof shows the tag and its owner
+	let record = TagRecord { name: vec![65, 66, 67], owner: 1, deposit: TagDepositAmount::get() };
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &header, 0, prove(0)), Ok(Some(record)));
+
+	// Or that there is no such tag
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &header, 1, prove(1)), Ok(None));
+
+	// Proofs only hold against the state root they were made for
+	let other = Header { state_root: BlakeTwo256::hash(b"other"), ..header.clone() };
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &other, 0, prove(0)), Err(ProofError::InvalidProof));
+	assert_eq!(verify_tag_proof::<_, u64, u64>(PALLET_NAME, &header, 0, vec![]), Err(ProofError::InvalidProof));
+
+	// And the value must decode as a tag
+	assert_eq!(verify_tag_proof::<_, [u8; 32], u64>(PALLET_NAME, &header, 0, prove(0)), Err(ProofError::InvalidValue));
+}
+
+#[test]
+fn tags_as_nfts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		let deposit = TagDepositAmount::get();
+
+		// Tags created before the collection aren't NFTs
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+
+		// Only the force origin creates the collection, once
+		assert_noop!(TagModule::create_tag_collection(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+		assert_ok!(TagModule::create_tag_collection(RuntimeOrigin::root()));
+		let collection = TagCollection::<Test>::get().unwrap();
+		System::assert_last_event(Event::TagCollectionCreated { collection }.into());
+		assert_eq!(Nfts::collection_owner(collection), Some(TagModule::account_id()));
+		assert_noop!(TagModule::create_tag_collection(RuntimeOrigin::root()), Error::<Test>::TagCollectionExists);
+
+		// New tags are minted to their owner
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert_eq!(Nfts::owner(collection, 1), Some(1));
+		assert_eq!(Nfts::owner(collection, 0), None);
+		assert_eq!(TagDepositors::<Test>::get(1), Some(1));
+
+		// Whoever holds the item owns the tag
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(1), collection, 1, 2));
+		assert_eq!(TagModule::item_owner(1), Some(2));
+		assert_eq!(TagModule::tag_info(1).unwrap().owner, 2);
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), 1, 5), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(2), 1, 5));
+
+		// A listing by the previous holder can't be bought anymore
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(2), collection, 1, 1));
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(1), 1, 10));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(1), collection, 1, 2));
+		assert_noop!(TagModule::buy_tag(RuntimeOrigin::signed(3), 1, 10), Error::<Test>::NotForSale);
+
+		// Anyone can bring the stored owner up to date, the deposit stays with its payer
+		assert_ok!(TagModule::sync_tag_owner(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(Event::TagTransferred { index: 1, from: 1, to: 2, updated_at: START }.into());
+		assert_eq!(TagMap::<Test>::get(1), Some((bvec![66], 2, deposit)));
+		assert_eq!(Listings::<Test>::get(1), None);
+		assert_eq!(pallet_tag_guard::Known::<Test>::get(1), Some(2));
+		assert_eq!(Balances::reserved_balance(&1), 2 * deposit);
+		assert_noop!(TagModule::sync_tag_owner(RuntimeOrigin::signed(3), 1), Error::<Test>::OwnerUpToDate);
+		assert_noop!(TagModule::sync_tag_owner(RuntimeOrigin::signed(3), 0), Error::<Test>::NotAnNft);
+
+		// Buying the tag moves the item
+		assert_ok!(TagModule::list_tag(RuntimeOrigin::signed(2), 1, 10));
+		assert_ok!(TagModule::buy_tag(RuntimeOrigin::signed(3), 1, 10));
+		assert_eq!(Nfts::owner(collection, 1), Some(3));
+		assert_eq!(TagDepositors::<Test>::get(1), Some(3));
+		assert_eq!(Balances::reserved_balance(&1), deposit);
+		assert_eq!(Balances::reserved_balance(&3), deposit);
+
+		// Destroying the tag burns the item
+		assert_ok!(TagModule::untag_account(RuntimeOrigin::signed(3), 1, 5));
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(3), 1));
+		assert_eq!(Nfts::owner(collection, 1), None);
+		assert_eq!(Balances::reserved_balance(&3), 0);
+
+		// Tags without an item keep working as before
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn tag_item_deposits() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		let deposit = TagDepositAmount::get() + 2;
+		NftsItemDeposit::set(&2);
+		assert_ok!(TagModule::create_tag_collection(RuntimeOrigin::root()));
+		let collection = TagCollection::<Test>::get().unwrap();
+
+		// The payer of a tag pays the deposit of its item, not the pallet
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_eq!(Balances::reserved_balance(&1), deposit);
+		assert_eq!(Balances::total_balance(&TagModule::account_id()), 0);
+
+		// Even when the tag is relayed for another owner
+		assert_ok!(TagModule::do_create_tag(2, bvec![66], 1));
+		assert_eq!(Nfts::owner(collection, 1), Some(2));
+		assert_eq!(Balances::reserved_balance(&1), 2 * deposit);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+
+		// Burning the item returns its deposit, and leaves the tag without an owner
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(1), collection, 0));
+		assert_eq!(Balances::reserved_balance(&1), 2 * deposit - 2);
+		assert_noop!(TagModule::tag_account(RuntimeOrigin::signed(1), 0, 5), Error::<Test>::TagItemBurned);
+		assert_noop!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0), Error::<Test>::TagItemBurned);
+		assert_noop!(TagModule::sync_tag_owner(RuntimeOrigin::signed(2), 0), Error::<Test>::NotAnNft);
+
+		// Anyone can remove it then, and the payer gets the deposit back
+		assert_noop!(TagModule::remove_burned_tag(RuntimeOrigin::signed(2), 1), Error::<Test>::TagItemNotBurned);
+		assert_ok!(TagModule::remove_burned_tag(RuntimeOrigin::signed(2), 0));
+		assert!(!TagMap::<Test>::contains_key(0));
+		assert_eq!(TagNames::<Test>::get(bvec![65]), None);
+		assert_eq!(Balances::reserved_balance(&1), deposit);
+
+		// Tags still in use are destroyed in steps
+		assert_ok!(TagModule::tag_account(RuntimeOrigin::signed(2), 1, 5));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(2), collection, 1));
+		assert_ok!(TagModule::remove_burned_tag(RuntimeOrigin::signed(3), 1));
+		assert_eq!(TagStatuses::<Test>::get(1), TagStatus::Destroying);
+		assert_ok!(TagModule::destroy_tag_references(RuntimeOrigin::signed(3), 1, 10));
+		assert_ok!(TagModule::finish_destroy_tag(RuntimeOrigin::signed(3), 1));
+		assert!(!TagMap::<Test>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn tag_scopes() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		TaggingMode::set(&TaggingPolicy::Open);
+		let acme = create_item(2);
+		let other = create_item(2);
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+
+		// Only the owner of the tag sets its scope
+		assert_noop!(TagModule::set_tag_scope(RuntimeOrigin::signed(3), 0, bvec![acme]), Error::<Test>::NotAllowed);
+		assert_ok!(TagModule::set_tag_scope(RuntimeOrigin::signed(1), 0, bvec![acme]));
+		System::assert_last_event(Event::TagScopeSet { index: 0, collections: bvec![acme], updated_at: START }.into());
+		assert_eq!(TagScopes::<Test>::get(0), Some(bvec![acme]));
+
+		// Items and collections outside the scope can't be tagged, or proposed
+		assert_noop!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, other, 0, 0), Error::<Test>::OutOfScope);
+		assert_noop!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, other), Error::<Test>::OutOfScope);
+		TaggingMode::set(&TaggingPolicy::ItemOwnerApproval);
+		assert_noop!(TagModule::propose_item_tag(RuntimeOrigin::signed(3), 0, other, 0, 0), Error::<Test>::OutOfScope);
+		TaggingMode::set(&TaggingPolicy::Open);
+
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, acme, 0, 0));
+		assert_ok!(TagModule::tag_collection(RuntimeOrigin::signed(1), 0, acme));
+
+		// An empty list removes the scope
+		assert_ok!(TagModule::set_tag_scope(RuntimeOrigin::signed(1), 0, bvec![]));
+		System::assert_last_event(Event::TagScopeSet { index: 0, collections: bvec![], updated_at: START }.into());
+		assert_eq!(TagScopes::<Test>::get(0), None);
+		assert_ok!(TagModule::tag_item(RuntimeOrigin::signed(3), 0, other, 0, 0));
+
+		// The scope goes away with the tag
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert_ok!(TagModule::set_tag_scope(RuntimeOrigin::signed(1), 1, bvec![acme, other]));
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 1));
+		assert_eq!(TagScopes::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn tag_staking() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+
+		assert_noop!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 0, 0), Error::<Test>::ZeroStake);
+		assert_noop!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 5, 10), Error::<Test>::InvalidTag);
+		assert_noop!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 0, 1), Error::<Test>::StakeTooLow);
+
+		// Stakes add up, and stakers aren't references of the tag
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 0, 10));
+		System::assert_last_event(Event::Staked { index: 0, who: 2, amount: 10, total: 10 }.into());
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(3), 0, 5));
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 0, 5));
+		System::assert_last_event(Event::Staked { index: 0, who: 2, amount: 5, total: 20 }.into());
+		assert_eq!(Stakes::<Test>::get(0, 2), Some(15));
+		assert_eq!(TagReferences::<Test>::get(0), 0);
+		assert_eq!(Balances::reserved_balance(&2), 15);
+
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(3), 1, 30));
+		assert_eq!(TagModule::top_tags_by_stake(5), vec![(1, 30), (0, 20)]);
+		assert_eq!(TagModule::top_tags_by_stake(1), vec![(1, 30)]);
+
+		// Unstaked funds stop counting at once, but stay reserved until they unlock
+		assert_noop!(TagModule::unstake(RuntimeOrigin::signed(2), 0, 20), Error::<Test>::NotEnoughStake);
+		assert_noop!(TagModule::unstake(RuntimeOrigin::signed(2), 0, 14), Error::<Test>::StakeTooLow);
+		assert_ok!(TagModule::unstake(RuntimeOrigin::signed(2), 0, 15));
+		System::assert_last_event(Event::Unstaked { index: 0, who: 2, amount: 15, unlock_at: 6 }.into());
+		assert_eq!(Stakes::<Test>::get(0, 2), None);
+		assert_eq!(TotalStakes::<Test>::get(0), 5);
+		assert_eq!(Balances::reserved_balance(&2), 15);
+		assert_noop!(TagModule::withdraw_unbonded(RuntimeOrigin::signed(2)), Error::<Test>::NothingToWithdraw);
+
+		assert_ok!(TagModule::unstake(RuntimeOrigin::signed(3), 1, 1));
+		assert_ok!(TagModule::unstake(RuntimeOrigin::signed(3), 1, 1));
+		assert_noop!(TagModule::unstake(RuntimeOrigin::signed(3), 1, 1), Error::<Test>::TooManyUnbondingChunks);
+
+		System::set_block_number(6);
+		assert_ok!(TagModule::withdraw_unbonded(RuntimeOrigin::signed(2)));
+		System::assert_last_event(Event::StakeWithdrawn { who: 2, amount: 15 }.into());
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Unbonding::<Test>::get(2), None);
+
+		// Stakes don't keep a tag from being destroyed, a task releases them without unbonding
+		assert_ok!(TagModule::destroy_tag(RuntimeOrigin::signed(1), 0));
+		assert_eq!(TagModule::top_tags_by_stake(5), vec![(1, 28)]);
+		assert_eq!(TotalStakes::<Test>::get(0), 0);
+		let purge = Task::<Test>::purge_stake { tag_index: 0, staker: 3 };
+		assert_eq!(Task::<Test>::iter().collect::<Vec<_>>(), vec![purge.clone()]);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge)));
+		System::assert_last_event(Event::StakeReleased { index: 0, who: 3, amount: 5 }.into());
+		assert_eq!(Balances::reserved_balance(&3), 30);
+
+		// So do moderators
+		assert_ok!(TagModule::report_tag(RuntimeOrigin::signed(2), 1, bvec![]));
+		assert_ok!(TagModule::uphold_report(RuntimeOrigin::root(), 1));
+		assert_eq!(TagModule::top_tags_by_stake(5), vec![]);
+		let purge = Task::<Test>::purge_stake { tag_index: 1, staker: 3 };
+		assert_eq!(Task::<Test>::iter().collect::<Vec<_>>(), vec![purge.clone()]);
+		assert_ok!(System::do_task(RuntimeOrigin::none(), RuntimeTask::TagModule(purge)));
+		System::assert_last_event(Event::StakeReleased { index: 1, who: 3, amount: 28 }.into());
+		assert_eq!(Balances::reserved_balance(&3), 2);
+	});
+}
+
+#[test]
+fn top_staked_tags() {
+	new_test_ext().execute_with(|| {
+		// Give some amount to the accounts so the test can run
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![65]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![66]));
+		assert_ok!(TagModule::create_tag(RuntimeOrigin::signed(1), bvec![67]));
+
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 0, 10));
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 1, 20));
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 2, 5));
+		assert_eq!(TagModule::top_tags_by_stake(5), vec![(1, 20), (0, 10), (2, 5)]);
+		assert_eq!(TagModule::top_tags_by_stake(2), vec![(1, 20), (0, 10)]);
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 2, 10));
+		assert_eq!(TagModule::top_tags_by_stake(2), vec![(1, 20), (2, 15)]);
+
+		// Tags move as their stake changes, and ties go to the oldest tag
+		assert_ok!(TagModule::unstake(RuntimeOrigin::signed(2), 1, 10));
+		assert_eq!(TagModule::top_tags_by_stake(5), vec![(2, 15), (0, 10), (1, 10)]);
+		assert_ok!(TagModule::stake_on_tag(RuntimeOrigin::signed(2), 0, 1));
+		assert_eq!(TagModule::top_tags_by_stake(2), vec![(2, 15), (0, 11)]);
+
+		// Tags that aren't live leave the ranking, and the next ones move up
+		assert_ok!(TagModule::start_destroy_tag(RuntimeOrigin::signed(1), 2));
+		assert_eq!(TagModule::top_tags_by_stake(2), vec![(0, 11), (1, 10)]);
+	});
+}
@@ -0,0 +1,76 @@
+//! Traits other pallets can implement to interact with tags.
+
+use core::marker::PhantomData;
+use frame_support::{dispatch::DispatchResult, traits::Get};
+
+use crate::TaggingPolicy;
+
+/// Hooks called by the tags pallet during the lifecycle of a tag, so other pallets can keep their
+/// own state in sync with it.
+///
+/// The weight of the hooks isn't included in the weight of the calls, so implementations should
+/// be cheap.
+pub trait OnTagLifecycle<AccountId> {
+	/// Called after a tag was created.
+	fn on_created(_tag: u64, _owner: &AccountId) {}
+
+	/// Called before a tag is destroyed by its owner. Returning an error prevents the destruction.
+	fn can_destroy(_tag: u64, _owner: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	/// Called after a tag was removed from storage.
+	fn on_destroyed(_tag: u64, _owner: &AccountId) {}
+
+	/// Called after the ownership of a tag moved to another account.
+	fn on_transferred(_tag: u64, _from: &AccountId, _to: &AccountId) {}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnTagLifecycle<AccountId> for Tuple {
+	fn on_created(tag: u64, owner: &AccountId) {
+		for_tuples!( #( Tuple::on_created(tag, owner); )* );
+	}
+
+	fn can_destroy(tag: u64, owner: &AccountId) -> DispatchResult {
+		for_tuples!( #( Tuple::can_destroy(tag, owner)?; )* );
+		Ok(())
+	}
+
+	fn on_destroyed(tag: u64, owner: &AccountId) {
+		for_tuples!( #( Tuple::on_destroyed(tag, owner); )* );
+	}
+
+	fn on_transferred(tag: u64, from: &AccountId, to: &AccountId) {
+		for_tuples!( #( Tuple::on_transferred(tag, from, to); )* );
+	}
+}
+
+/// Decides who can apply a tag to the items of a collection.
+pub trait TagPermission<CollectionId> {
+	/// The policy for applying `tag` to the items of `collection`.
+	fn policy(tag: u64, collection: &CollectionId) -> TaggingPolicy;
+}
+
+/// Applies the same policy to every tag and collection.
+pub struct FixedPolicy<P>(PhantomData<P>);
+
+impl<P: Get<TaggingPolicy>, CollectionId> TagPermission<CollectionId> for FixedPolicy<P> {
+	fn policy(_tag: u64, _collection: &CollectionId) -> TaggingPolicy {
+		P::get()
+	}
+}
+
+/// Provides the keys and signatures the benchmarks need for pre-signed payloads, and the items
+/// they tag.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, Signature, AccountId, CollectionId, ItemId> {
+	/// Creates a new key pair, returning the public key.
+	fn create_signer() -> Public;
+
+	/// Signs the message with the key pair of `signer`.
+	fn sign(signer: &Public, message: &[u8]) -> Signature;
+
+	/// Creates a new item owned by `owner`, in a new collection.
+	fn create_item(owner: &AccountId) -> (CollectionId, ItemId);
+}
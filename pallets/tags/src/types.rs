@@ -0,0 +1,120 @@
+//! Types used by the tags pallet.
+
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+use crate::Config;
+
+/// Tag creation data signed by the future owner of the tag, so a relayer can submit it on its
+/// behalf.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PreSignedTag<Name, BlockNumber> {
+	/// The name of the tag.
+	pub name: Name,
+	/// The last block in which the payload can be submitted.
+	pub deadline: BlockNumber,
+	/// The nonce of the signer, which must match the one stored on chain.
+	pub nonce: u64,
+	/// Whether the deposit is reserved from the signer, or from the relayer.
+	pub signer_pays_deposit: bool,
+}
+
+/// An NFT, identified by its collection and its id within the collection.
+pub type ItemOf<T> = (<T as Config>::CollectionId, <T as Config>::ItemId);
+
+pub type PreSignedTagOf<T> =
+	PreSignedTag<BoundedVec<u8, <T as Config>::TagNameLimit>, BlockNumberFor<T>>;
+
+/// The lifecycle status of a tag.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub enum TagStatus {
+	/// The tag can be used normally.
+	#[default]
+	Live,
+	/// The tag is being destroyed, and can't be applied anymore.
+	Destroying,
+	/// The references to the tag are being moved to another tag, and it can't be applied
+	/// anymore.
+	Merging {
+		/// The tag the references are moved to.
+		into: u64,
+	},
+}
+
+/// Who can apply a tag to an item.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TaggingPolicy {
+	/// Anyone can apply the tag.
+	Open,
+	/// Only the owner of the tag can apply it.
+	TagOwner,
+	/// Anyone can propose the tag, and it's only applied once the owner of the item accepts it.
+	/// The owner of the item can apply it directly.
+	ItemOwnerApproval,
+}
+
+/// A point in time, as a block number and the timestamp of the block.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct BlockTime<BlockNumber, Moment> {
+	/// The block number.
+	pub block: BlockNumber,
+	/// The timestamp of the block.
+	pub moment: Moment,
+}
+
+pub type BlockTimeOf<T> = BlockTime<
+	BlockNumberFor<T>,
+	<<T as Config>::Time as frame_support::traits::Time>::Moment,
+>;
+
+/// When a tag was created, last changed and last applied to something.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct TagActivity<Time> {
+	/// When the tag was created.
+	pub created_at: Time,
+	/// When the tag itself last changed: its owner, listing, translations or usage fee.
+	pub updated_at: Time,
+	/// When the tag was last applied to an account, an item or a collection, if ever.
+	pub last_tagged_at: Option<Time>,
+}
+
+pub type TagActivityOf<T> = TagActivity<BlockTimeOf<T>>;
+
+/// Everything about a tag, as returned by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TagInfo<AccountId, Balance, Time> {
+	/// The name of the tag.
+	pub name: Vec<u8>,
+	/// The owner of the tag.
+	pub owner: AccountId,
+	/// The deposit reserved for the tag.
+	pub deposit: Balance,
+	/// The lifecycle status of the tag.
+	pub status: TagStatus,
+	/// When the tag was created, changed and applied. Zero for tags created before it was
+	/// recorded.
+	pub activity: TagActivity<Time>,
+}
+
+pub type TagInfoOf<T> = TagInfo<
+	<T as frame_system::Config>::AccountId,
+	crate::BalanceOf<T>,
+	BlockTimeOf<T>,
+>;
+
+/// What is left of a destroyed tag.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Tombstone<AccountId, BlockNumber> {
+	/// The owner of the tag when it was destroyed or merged, `None` if it was removed by
+	/// moderation.
+	pub destroyed_by: Option<AccountId>,
+	/// The block the tag was removed in.
+	pub destroyed_at: BlockNumber,
+	/// The tag it was merged into, if any.
+	pub successor: Option<u64>,
+}
+
+pub type TombstoneOf<T> = Tombstone<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
@@ -13,6 +13,8 @@ use sp_runtime::{
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
+#[cfg(feature = "runtime-benchmarks")]
+use sp_runtime::MultiSigner;
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
@@ -22,8 +24,8 @@ use frame_support::genesis_builder_helper::{build_config, create_default_config}
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-		StorageInfo,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
+		KeyOwnerProofSystem, Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -31,9 +33,11 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_nfts::PalletFeatures;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -57,6 +61,12 @@ pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::Account
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier of an NFT collection.
+pub type CollectionId = u32;
+
+/// Identifier of an NFT within its collection.
+pub type ItemId = u32;
+
 /// Index of a transaction in the chain.
 pub type Nonce = u32;
 
@@ -249,9 +259,85 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const NftsCollectionDeposit: Balance = 1 * DOLLARS;
+	pub const NftsItemDeposit: Balance = 1 * CENTS;
+	pub const NftsMetadataDepositBase: Balance = 1 * CENTS;
+	pub const NftsAttributeDepositBase: Balance = 1 * CENTS;
+	pub const NftsDepositPerByte: Balance = 10 * MILLICENTS;
+	pub const NftsStringLimit: u32 = 256;
+	pub const NftsKeyLimit: u32 = 64;
+	pub const NftsValueLimit: u32 = 256;
+	pub const NftsApprovalsLimit: u32 = 20;
+	pub const NftsItemAttributesApprovalsLimit: u32 = 20;
+	pub const NftsMaxTips: u32 = 10;
+	pub const NftsMaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const NftsMaxAttributesPerCall: u32 = 10;
+	pub NftsFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = ItemId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Locker = ();
+	type CollectionDeposit = NftsCollectionDeposit;
+	type ItemDeposit = NftsItemDeposit;
+	type MetadataDepositBase = NftsMetadataDepositBase;
+	type AttributeDepositBase = NftsAttributeDepositBase;
+	type DepositPerByte = NftsDepositPerByte;
+	type StringLimit = NftsStringLimit;
+	type KeyLimit = NftsKeyLimit;
+	type ValueLimit = NftsValueLimit;
+	type ApprovalsLimit = NftsApprovalsLimit;
+	type ItemAttributesApprovalsLimit = NftsItemAttributesApprovalsLimit;
+	type MaxTips = NftsMaxTips;
+	type MaxDeadlineDuration = NftsMaxDeadlineDuration;
+	type MaxAttributesPerCall = NftsMaxAttributesPerCall;
+	type Features = NftsFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
 parameter_types! {
 	pub const TagNameLimit: u32 = 256;
 	pub const TagDepositAmount: Balance = 1 * CENTS;
+	pub const TagReportBond: Balance = 10 * MILLICENTS;
+	pub const TagReportReasonLimit: u32 = 128;
+	pub const TagCommitmentDeposit: Balance = 10 * MILLICENTS;
+	pub const MinTagCommitmentAge: BlockNumber = 1 * MINUTES;
+	pub const MaxTagCommitmentAge: BlockNumber = 1 * DAYS;
+	pub const TagLanguageCodeLimit: u32 = 8;
+	pub const TagTranslationLimit: u32 = 256;
+	pub const MaxTagTranslations: u32 = 16;
+	pub const TagTranslationDepositPerByte: Balance = 4 * MILLICENTS;
+	pub const RemoveTagReferencesLimit: u32 = 500;
+	pub const MaxTagsPerItem: u32 = 32;
+	/// Wallets that only read NFT attributes can see the tags of items.
+	pub const MirrorTagsToAttributes: bool = true;
+	/// Anyone can propose a tag for an item, but only its owner decides whether it's applied.
+	pub const TagItemPolicy: pallet_tags::TaggingPolicy = pallet_tags::TaggingPolicy::ItemOwnerApproval;
+	pub const TagProposalDeposit: Balance = 10 * MILLICENTS;
+	pub const TaggingDeposit: Balance = 2 * MILLICENTS;
+	/// Item owners approve every tag, so removing one later isn't a sign of spam.
+	pub const SlashRemovedTaggings: bool = false;
+	pub const MaxTagUsageFee: Balance = 1 * DOLLARS;
+	pub const MaxQueryTags: u32 = 16;
+	pub const TagsPalletId: PalletId = PalletId(*b"py/tags_");
+	pub const MaxScopeCollections: u32 = 16;
+	pub const TagStakeUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxTagUnbondingChunks: u32 = 8;
+	pub const MinTagStake: Balance = 1 * CENTS;
+	/// Accounts carrying this tag can moderate tags. Governance picks the tag, and so whoever
+	/// decides who carries it, by setting this value with `set_storage`. Until then it's an id no
+	/// tag gets, so only root moderates.
+	pub storage ModeratorTag: u64 = u64::MAX;
 }
 
 /// Configure the pallet-template in pallets/template.
@@ -262,6 +348,79 @@ impl pallet_tags::Config for Runtime {
 
 	type TagNameLimit = TagNameLimit;
 	type TagDepositAmount = TagDepositAmount;
+
+	type ModeratorOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_tags::EnsureTagged<Runtime, ModeratorTag>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	/// There is no treasury in this runtime yet, so slashed funds are burned.
+	type Slash = ();
+	type ReportBond = TagReportBond;
+	type ReportReasonLimit = TagReportReasonLimit;
+	type OnTagLifecycle = ();
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type CommitmentDeposit = TagCommitmentDeposit;
+	type MinCommitmentAge = MinTagCommitmentAge;
+	type MaxCommitmentAge = MaxTagCommitmentAge;
+	type LanguageCodeLimit = TagLanguageCodeLimit;
+	type TranslationLimit = TagTranslationLimit;
+	type MaxTranslations = MaxTagTranslations;
+	type TranslationDepositPerByte = TagTranslationDepositPerByte;
+	type RemoveReferencesLimit = RemoveTagReferencesLimit;
+	type CollectionId = CollectionId;
+	type ItemId = ItemId;
+	type Nfts = Nfts;
+	type MaxTagsPerItem = MaxTagsPerItem;
+	type ItemConfig = pallet_nfts::ItemConfig;
+	type MirrorToAttributes = MirrorTagsToAttributes;
+	type TagPermission = pallet_tags::FixedPolicy<TagItemPolicy>;
+	type ProposalDeposit = TagProposalDeposit;
+	type TaggingDeposit = TaggingDeposit;
+	type SlashRemovedTaggings = SlashRemovedTaggings;
+	type MaxUsageFee = MaxTagUsageFee;
+	type Time = Timestamp;
+	type MaxQueryTags = MaxQueryTags;
+	type CollectionConfig = pallet_nfts::CollectionConfigFor<Runtime>;
+	type PalletId = TagsPalletId;
+	type MaxScopeCollections = MaxScopeCollections;
+	type StakeUnbondingPeriod = TagStakeUnbondingPeriod;
+	type MaxUnbondingChunks = MaxTagUnbondingChunks;
+	type MinStake = MinTagStake;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TagsBenchmarkHelper;
+}
+
+/// Signs the pre-signed tags of the benchmarks with a fresh sr25519 key, and mints the items they
+/// tag.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TagsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_tags::BenchmarkHelper<MultiSigner, Signature, AccountId, CollectionId, ItemId>
+	for TagsBenchmarkHelper
+{
+	fn create_signer() -> MultiSigner {
+		sp_io::crypto::sr25519_generate(0.into(), None).into()
+	}
+
+	fn sign(signer: &MultiSigner, message: &[u8]) -> Signature {
+		let MultiSigner::Sr25519(public) = signer else {
+			panic!("the benchmarks only create sr25519 signers");
+		};
+		sp_io::crypto::sr25519_sign(0.into(), public, message)
+			.expect("the key was generated in the keystore")
+			.into()
+	}
+
+	fn create_item(owner: &AccountId) -> (CollectionId, ItemId) {
+		let collection = pallet_nfts::NextCollectionId::<Runtime>::get().unwrap_or_default();
+		let owner_lookup = sp_runtime::MultiAddress::Id(owner.clone());
+		Nfts::force_create(RuntimeOrigin::root(), owner_lookup.clone(), Default::default())
+			.expect("root can always create collections");
+		Nfts::mint(RuntimeOrigin::signed(owner.clone()), collection, 0, owner_lookup, None)
+			.expect("the owner of the collection can mint");
+		(collection, 0)
+	}
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -305,6 +464,9 @@ mod runtime {
 	// Include the custom logic from the pallet-template in the runtime.
 	#[runtime::pallet_index(7)]
 	pub type TagModule = pallet_tags;
+
+	#[runtime::pallet_index(8)]
+	pub type Nfts = pallet_nfts;
 }
 
 /// The address format for describing accounts.
@@ -355,6 +517,7 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
 		[pallet_tags, TagModule]
+		[pallet_nfts, Nfts]
 	);
 }
 
@@ -526,6 +689,83 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_tags_runtime_api::TagsApi<
+		Block,
+		CollectionId,
+		ItemId,
+		AccountId,
+		Balance,
+		pallet_tags::TagInfoOf<Runtime>,
+		pallet_tags::TombstoneOf<Runtime>,
+	> for Runtime {
+		fn tag_info(tag: u64) -> Option<pallet_tags::TagInfoOf<Runtime>> {
+			TagModule::tag_info(tag)
+		}
+
+		fn tombstone(tag: u64) -> Option<pallet_tags::TombstoneOf<Runtime>> {
+			TagModule::tombstone(tag)
+		}
+
+		fn localized_name(tag: u64, lang: Vec<u8>) -> Option<Vec<u8>> {
+			TagModule::localized_name(tag, &lang)
+		}
+
+		fn resolve_tag(tag: u64) -> Option<u64> {
+			TagModule::resolve_tag(tag)
+		}
+
+		fn tag_by_name(name: Vec<u8>) -> Option<u64> {
+			TagModule::tag_by_name(&name)
+		}
+
+		fn items_by_tag(
+			tag: u64,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Vec<(CollectionId, ItemId)> {
+			TagModule::items_by_tag(tag, cursor, limit)
+		}
+
+		fn tags_by_item(
+			collection: CollectionId,
+			item: ItemId,
+			cursor: Option<u64>,
+			limit: u32,
+		) -> Vec<u64> {
+			TagModule::tags_by_item((collection, item), cursor, limit)
+		}
+
+		fn effective_tags(collection: CollectionId, item: ItemId) -> Vec<u64> {
+			TagModule::effective_tags((collection, item))
+		}
+
+		fn tagging_cost(tag: u64, who: AccountId) -> Option<Balance> {
+			TagModule::tagging_cost(tag, &who)
+		}
+
+		fn top_tags_by_stake(limit: u32) -> Vec<(u64, Balance)> {
+			TagModule::top_tags_by_stake(limit)
+		}
+
+		fn items_with_all_tags(
+			include: Vec<u64>,
+			exclude: Vec<u64>,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Option<(Vec<(CollectionId, ItemId)>, Option<(CollectionId, ItemId)>)> {
+			TagModule::items_with_all_tags(&include, &exclude, cursor, limit)
+		}
+
+		fn items_with_any_tag(
+			include: Vec<u64>,
+			exclude: Vec<u64>,
+			cursor: Option<(CollectionId, ItemId)>,
+			limit: u32,
+		) -> Option<(Vec<(CollectionId, ItemId)>, Option<(CollectionId, ItemId)>)> {
+			TagModule::items_with_any_tag(&include, &exclude, cursor, limit)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (